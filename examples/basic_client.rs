@@ -20,7 +20,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-#[cfg(feature = "sync")]
+#[cfg(all(feature = "sync", not(feature = "async")))]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create configuration for dev environment
     let config = Config::new(Environment::Dev);
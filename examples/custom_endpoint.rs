@@ -0,0 +1,43 @@
+//! Calling an endpoint the SDK does not wrap yet
+//!
+//! Define a type for the response and use `get` / `post`; the request
+//! is authenticated and errors are handled like any other SDK call.
+
+use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+use serde::Deserialize;
+
+/// Response of a hypothetical `GET /cards/{id}/rewards` endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct CardRewards {
+    points: u64,
+    tier: String,
+}
+
+#[cfg(feature = "async")]
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::new(Environment::Dev);
+    let auth = AuthConfig::with_api_key("your-api-key-here".to_string());
+    let client = RainClient::new(config, auth)?;
+
+    let card_id = uuid::Uuid::new_v4();
+    let rewards: CardRewards = client.get(&format!("/cards/{card_id}/rewards")).await?;
+    println!("Rewards: {rewards:?}");
+
+    Ok(())
+}
+
+#[cfg(all(feature = "sync", not(feature = "async")))]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::new(Environment::Dev);
+    let auth = AuthConfig::with_api_key("your-api-key-here".to_string());
+    let client = RainClient::new(config, auth)?;
+
+    let card_id = uuid::Uuid::new_v4();
+    let rewards: CardRewards = client.get_blocking(&format!("/cards/{card_id}/rewards"))?;
+    println!("Rewards: {rewards:?}");
+
+    Ok(())
+}
@@ -88,7 +88,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
         name: None,
-        file_path: "/path/to/id_front.jpg".to_string(),
+        source: DocumentSource::Path("/path/to/id_front.jpg".into()),
+        mime_type: None,
     };
 
     client
@@ -103,7 +104,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
         name: None,
-        file_path: "/path/to/id_back.jpg".to_string(),
+        source: DocumentSource::Path("/path/to/id_back.jpg".into()),
+        mime_type: None,
     };
 
     client
@@ -180,7 +182,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let status = user
             .application_status
             .unwrap_or(ApplicationStatus::Pending);
-        println!("Current status: {:?}", status);
+        println!("Current status: {status:?}");
 
         match status {
             ApplicationStatus::Approved => {
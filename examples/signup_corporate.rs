@@ -146,7 +146,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
         name: Some("Directors Registry".to_string()),
-        file_path: "/path/to/directors_registry.pdf".to_string(),
+        source: DocumentSource::Path("/path/to/directors_registry.pdf".into()),
+        mime_type: None,
     };
 
     client
@@ -174,7 +175,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
         name: None,
-        file_path: "/path/to/ubo_id_front.jpg".to_string(),
+        source: DocumentSource::Path("/path/to/ubo_id_front.jpg".into()),
+        mime_type: None,
     };
 
     client
@@ -237,7 +239,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let status = company
             .application_status
             .unwrap_or(ApplicationStatus::Pending);
-        println!("Current status: {:?}", status);
+        println!("Current status: {status:?}");
 
         match status {
             ApplicationStatus::Approved => {
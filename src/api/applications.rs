@@ -159,6 +159,48 @@ impl RainClient {
         self.patch(&path, request).await
     }
 
+    /// Update only the entity details of a company application
+    ///
+    /// Sends an [`UpdateCompanyApplicationRequest`] with just `entity` set, so the
+    /// name and address are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_id` - The unique identifier of the company
+    /// * `entity` - The entity fields to change, see [`EntityUpdateInfo::builder`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::applications::EntityUpdateInfo;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let company_id = Uuid::new_v4();
+    /// let entity = EntityUpdateInfo::builder().website("https://example.com").build();
+    /// let application = client.update_company_entity(&company_id, entity).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn update_company_entity(
+        &self,
+        company_id: &Uuid,
+        entity: EntityUpdateInfo,
+    ) -> Result<CompanyApplicationResponse> {
+        let request = UpdateCompanyApplicationRequest {
+            entity: Some(entity),
+            ..Default::default()
+        };
+        self.update_company_application(company_id, &request).await
+    }
+
     /// Update an ultimate beneficial owner
     ///
     /// # Arguments
@@ -233,7 +275,7 @@ impl RainClient {
     ///
     /// ```no_run
     /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
-    /// use rain_sdk::models::applications::DocumentUploadParams;
+    /// use rain_sdk::models::applications::{DocumentSource, DocumentUploadParams};
     /// use uuid::Uuid;
     ///
     /// # #[cfg(feature = "async")]
@@ -249,7 +291,8 @@ impl RainClient {
     ///     country: Some("US".to_string()),
     ///     country_code: Some("US".to_string()),
     ///     name: Some("Document Name".to_string()),
-    ///     file_path: "/path/to/file.pdf".to_string(),
+    ///     source: DocumentSource::Path("/path/to/file.pdf".into()),
+    ///     mime_type: None,
     /// };
     /// client.upload_company_document(&company_id, &params).await?;
     /// # Ok(())
@@ -282,7 +325,7 @@ impl RainClient {
     ///
     /// ```no_run
     /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
-    /// use rain_sdk::models::applications::DocumentUploadParams;
+    /// use rain_sdk::models::applications::{DocumentSource, DocumentUploadParams};
     /// use uuid::Uuid;
     ///
     /// # #[cfg(feature = "async")]
@@ -299,7 +342,8 @@ impl RainClient {
     ///     country: Some("US".to_string()),
     ///     country_code: Some("US".to_string()),
     ///     name: None,
-    ///     file_path: "/path/to/file.pdf".to_string(),
+    ///     source: DocumentSource::Path("/path/to/file.pdf".into()),
+    ///     mime_type: None,
     /// };
     /// client.upload_ubo_document(&company_id, &ubo_id, &params).await?;
     /// # Ok(())
@@ -317,6 +361,34 @@ impl RainClient {
         self.put_multipart(&path, form).await
     }
 
+    /// Upload a document for a company representative
+    ///
+    /// The Rain API does not expose a document upload endpoint for representatives;
+    /// their identity is verified as part of the company application. This method
+    /// exists so callers get an explicit error instead of guessing at a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_id` - The unique identifier of the company
+    /// * `representative_id` - The unique identifier of the representative
+    /// * `params` - Document upload parameters
+    ///
+    /// # Errors
+    ///
+    /// Always returns [`RainError::Unsupported`](crate::error::RainError::Unsupported).
+    #[cfg(feature = "async")]
+    pub async fn upload_representative_document(
+        &self,
+        company_id: &Uuid,
+        representative_id: &Uuid,
+        _params: &DocumentUploadParams,
+    ) -> Result<serde_json::Value> {
+        Err(crate::error::RainError::Unsupported(format!(
+            "representative {representative_id} of company {company_id} has no document \
+             upload endpoint; representatives are verified through the company application"
+        )))
+    }
+
     // ============================================================================
     // User Application Methods
     // ============================================================================
@@ -507,6 +579,8 @@ impl RainClient {
         &self,
         request: &CreateUserApplicationRequest,
     ) -> Result<UserApplicationResponse> {
+        #[cfg(feature = "validate-schema")]
+        crate::schema::check(crate::schema::Schema::CreateUserApplicationRequest, request)?;
         let path = "/applications/user";
         self.post(path, request).await
     }
@@ -661,6 +735,62 @@ impl RainClient {
         self.patch(&path, request).await
     }
 
+    /// Start a new verification cycle for an existing user
+    ///
+    /// Patches the user's application with only `hasExistingDocuments` set, for
+    /// periodic re-KYC. The response carries the new verification link, if any;
+    /// see [`UserApplicationResponse::next_step`].
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `has_existing_documents` - Whether the documents already on file can be reused
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`UserApplicationResponse`] containing the refreshed application.
+    ///
+    /// # Errors
+    ///
+    /// This method can return the following errors:
+    /// - `400` - Invalid request
+    /// - `401` - Invalid authorization
+    /// - `404` - User not found
+    /// - `500` - Internal server error
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::applications::NextStep;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let application = client.refresh_user_verification(&user_id, true).await?;
+    /// if let Some(NextStep::Verify(url)) = application.next_step() {
+    ///     println!("Send the user to {url}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`UserApplicationResponse::next_step`]: crate::models::applications::UserApplicationResponse::next_step
+    #[cfg(feature = "async")]
+    pub async fn refresh_user_verification(
+        &self,
+        user_id: &Uuid,
+        has_existing_documents: bool,
+    ) -> Result<UserApplicationResponse> {
+        let request = refresh_request(has_existing_documents);
+        self.update_user_application(user_id, &request).await
+    }
+
     /// Upload a document for a user application
     ///
     /// # Arguments
@@ -676,7 +806,7 @@ impl RainClient {
     ///
     /// ```no_run
     /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
-    /// use rain_sdk::models::applications::DocumentUploadParams;
+    /// use rain_sdk::models::applications::{DocumentSource, DocumentUploadParams};
     /// use uuid::Uuid;
     ///
     /// # #[cfg(feature = "async")]
@@ -692,7 +822,8 @@ impl RainClient {
     ///     country: Some("US".to_string()),
     ///     country_code: Some("US".to_string()),
     ///     name: Some("ID Card".to_string()),
-    ///     file_path: "/path/to/file.pdf".to_string(),
+    ///     source: DocumentSource::Path("/path/to/file.pdf".into()),
+    ///     mime_type: None,
     /// };
     /// client.upload_user_document(&user_id, &params).await?;
     /// # Ok(())
@@ -709,6 +840,67 @@ impl RainClient {
         self.put_multipart(&path, form).await
     }
 
+    /// Upload a document for a user application, reporting progress
+    ///
+    /// The file is streamed from disk in chunks rather than read into memory first,
+    /// and `on_progress` is called with `(bytes_sent, total_bytes)` as each chunk is
+    /// handed to the connection, ending with `bytes_sent == total_bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `params` - Document upload parameters
+    /// * `on_progress` - Called with the bytes sent so far and the file size
+    ///
+    /// # Returns
+    ///
+    /// Returns a success response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::applications::{DocumentSource, DocumentUploadParams};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let params = DocumentUploadParams {
+    ///     document_type: "idCard".to_string(),
+    ///     side: "front".to_string(),
+    ///     country: None,
+    ///     country_code: None,
+    ///     name: None,
+    ///     source: DocumentSource::Path("passport.pdf".into()),
+    ///     mime_type: None,
+    /// };
+    /// client
+    ///     .upload_user_document_with_progress(&user_id, &params, |sent, total| {
+    ///         println!("{sent}/{total} bytes");
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn upload_user_document_with_progress(
+        &self,
+        user_id: &Uuid,
+        params: &DocumentUploadParams,
+        on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> Result<serde_json::Value> {
+        let path = format!("/applications/user/{user_id}/document");
+        let form = self
+            .build_user_document_form_with_progress(params, on_progress)
+            .await?;
+        self.put_multipart(&path, form).await
+    }
+
     // ============================================================================
     // Helper Methods
     // ============================================================================
@@ -718,44 +910,9 @@ impl RainClient {
         &self,
         params: &DocumentUploadParams,
     ) -> Result<reqwest::multipart::Form> {
-        use std::fs;
-
-        let file_bytes = fs::read(&params.file_path).map_err(|e| {
-            crate::error::RainError::Other(anyhow::anyhow!("Failed to read file: {e}"))
-        })?;
-
-        let file_name = params
-            .file_path
-            .split('/')
-            .next_back()
-            .unwrap_or("document")
-            .to_string();
-
-        let part = reqwest::multipart::Part::bytes(file_bytes)
-            .file_name(file_name)
-            .mime_str("application/octet-stream")
-            .map_err(|e| {
-                crate::error::RainError::Other(anyhow::anyhow!("Invalid MIME type: {e}"))
-            })?;
-
-        let mut form = reqwest::multipart::Form::new()
-            .part("document", part)
-            .text("type", params.document_type.clone())
-            .text("side", params.side.clone());
-
-        if let Some(ref name) = params.name {
-            form = form.text("name", name.clone());
-        }
-
-        if let Some(ref country) = params.country {
-            form = form.text("country", country.clone());
-        }
-
-        if let Some(ref country_code) = params.country_code {
-            form = form.text("countryCode", country_code.clone());
-        }
-
-        Ok(form)
+        let (file_bytes, file_name) = read_document(params)?;
+        let part = reqwest::multipart::Part::bytes(file_bytes).file_name(file_name);
+        document_form(params, part)
     }
 
     #[cfg(feature = "async")]
@@ -763,44 +920,44 @@ impl RainClient {
         &self,
         params: &DocumentUploadParams,
     ) -> Result<reqwest::multipart::Form> {
-        use std::fs;
-
-        let file_bytes = fs::read(&params.file_path).map_err(|e| {
-            crate::error::RainError::Other(anyhow::anyhow!("Failed to read file: {e}"))
-        })?;
-
-        let file_name = params
-            .file_path
-            .split('/')
-            .next_back()
-            .unwrap_or("document")
-            .to_string();
-
-        let part = reqwest::multipart::Part::bytes(file_bytes)
-            .file_name(file_name)
-            .mime_str("application/octet-stream")
-            .map_err(|e| {
-                crate::error::RainError::Other(anyhow::anyhow!("Invalid MIME type: {e}"))
-            })?;
-
-        let mut form = reqwest::multipart::Form::new()
-            .part("document", part)
-            .text("type", params.document_type.clone())
-            .text("side", params.side.clone());
+        let (file_bytes, file_name) = read_document(params)?;
+        let part = reqwest::multipart::Part::bytes(file_bytes).file_name(file_name);
+        document_form(params, part)
+    }
 
-        if let Some(ref name) = params.name {
-            form = form.text("name", name.clone());
-        }
+    #[cfg(feature = "async")]
+    async fn build_user_document_form_with_progress(
+        &self,
+        params: &DocumentUploadParams,
+        on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> Result<reqwest::multipart::Form> {
+        use futures_util::TryStreamExt;
+        use tokio::io::AsyncRead;
+        use tokio_util::io::ReaderStream;
 
-        if let Some(ref country) = params.country {
-            form = form.text("country", country.clone());
-        }
+        let (reader, total): (Box<dyn AsyncRead + Send + Unpin>, u64) = match &params.source {
+            DocumentSource::Bytes { bytes, .. } => (
+                Box::new(std::io::Cursor::new(bytes.clone())),
+                bytes.len() as u64,
+            ),
+            DocumentSource::Path(path) => {
+                let file = tokio::fs::File::open(path).await.map_err(read_file_error)?;
+                let total = file.metadata().await.map_err(read_file_error)?.len();
+                (Box::new(file), total)
+            }
+        };
 
-        if let Some(ref country_code) = params.country_code {
-            form = form.text("countryCode", country_code.clone());
-        }
+        let mut sent = 0;
+        let body =
+            ReaderStream::with_capacity(reader, UPLOAD_CHUNK_SIZE).inspect_ok(move |chunk| {
+                sent += chunk.len() as u64;
+                on_progress(sent, total);
+            });
 
-        Ok(form)
+        let part =
+            reqwest::multipart::Part::stream_with_length(reqwest::Body::wrap_stream(body), total)
+                .file_name(document_file_name(params));
+        document_form(params, part)
     }
 
     // ============================================================================
@@ -838,6 +995,20 @@ impl RainClient {
         self.patch_blocking(&path, request)
     }
 
+    /// Update only the entity details of a company application (blocking)
+    #[cfg(feature = "sync")]
+    pub fn update_company_entity_blocking(
+        &self,
+        company_id: &Uuid,
+        entity: EntityUpdateInfo,
+    ) -> Result<CompanyApplicationResponse> {
+        let request = UpdateCompanyApplicationRequest {
+            entity: Some(entity),
+            ..Default::default()
+        };
+        self.update_company_application_blocking(company_id, &request)
+    }
+
     /// Update an ultimate beneficial owner (blocking)
     #[cfg(feature = "sync")]
     pub fn update_ultimate_beneficial_owner_blocking(
@@ -856,6 +1027,8 @@ impl RainClient {
         &self,
         request: &CreateUserApplicationRequest,
     ) -> Result<UserApplicationResponse> {
+        #[cfg(feature = "validate-schema")]
+        crate::schema::check(crate::schema::Schema::CreateUserApplicationRequest, request)?;
         let path = "/applications/user";
         self.post_blocking(path, request)
     }
@@ -887,4 +1060,90 @@ impl RainClient {
         let path = format!("/applications/user/{user_id}");
         self.patch_blocking(&path, request)
     }
+
+    /// Start a new verification cycle for an existing user (blocking)
+    #[cfg(feature = "sync")]
+    pub fn refresh_user_verification_blocking(
+        &self,
+        user_id: &Uuid,
+        has_existing_documents: bool,
+    ) -> Result<UserApplicationResponse> {
+        let request = refresh_request(has_existing_documents);
+        self.update_user_application_blocking(user_id, &request)
+    }
+}
+
+/// Update that only asks for a new verification cycle
+#[cfg(any(feature = "async", feature = "sync"))]
+fn refresh_request(has_existing_documents: bool) -> UpdateUserApplicationRequest {
+    UpdateUserApplicationRequest {
+        has_existing_documents: Some(has_existing_documents),
+        ..Default::default()
+    }
+}
+
+/// Size of the chunks a document is streamed in when reporting upload progress
+#[cfg(feature = "async")]
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Get a document's contents and file name, reading the file for a path source
+#[cfg(feature = "async")]
+fn read_document(params: &DocumentUploadParams) -> Result<(Vec<u8>, String)> {
+    let file_bytes = match &params.source {
+        DocumentSource::Bytes { bytes, .. } => bytes.clone(),
+        DocumentSource::Path(path) => std::fs::read(path).map_err(read_file_error)?,
+    };
+    Ok((file_bytes, document_file_name(params)))
+}
+
+/// File name a document is uploaded under
+#[cfg(feature = "async")]
+fn document_file_name(params: &DocumentUploadParams) -> String {
+    match &params.source {
+        DocumentSource::Bytes { name, .. } => name.clone(),
+        DocumentSource::Path(path) => path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "document".to_string()),
+    }
+}
+
+/// Wrap an error reading a document from disk
+#[cfg(feature = "async")]
+fn read_file_error(err: std::io::Error) -> crate::error::RainError {
+    crate::error::RainError::Other(anyhow::anyhow!("Failed to read file: {err}"))
+}
+
+/// Build a document upload form around the file part
+#[cfg(feature = "async")]
+fn document_form(
+    params: &DocumentUploadParams,
+    part: reqwest::multipart::Part,
+) -> Result<reqwest::multipart::Form> {
+    let mime_type = params
+        .mime_type
+        .as_deref()
+        .unwrap_or("application/octet-stream");
+    let part = part
+        .mime_str(mime_type)
+        .map_err(|e| crate::error::RainError::Other(anyhow::anyhow!("Invalid MIME type: {e}")))?;
+
+    let mut form = reqwest::multipart::Form::new()
+        .part("document", part)
+        .text("type", params.document_type.clone())
+        .text("side", params.side.clone());
+
+    if let Some(ref name) = params.name {
+        form = form.text("name", name.clone());
+    }
+
+    if let Some(ref country) = params.country {
+        form = form.text("country", country.clone());
+    }
+
+    if let Some(ref country_code) = params.country_code {
+        form = form.text("countryCode", country_code.clone());
+    }
+
+    Ok(form)
 }
@@ -2,6 +2,8 @@
 //!
 //! This module provides functionality to retrieve balances for tenants, companies, and users.
 
+#[cfg(feature = "async")]
+use crate::client::bulk::{self, BulkMode};
 use crate::client::RainClient;
 use crate::error::Result;
 use crate::models::balances::BalanceResponse;
@@ -125,6 +127,68 @@ impl RainClient {
         self.get(&path).await
     }
 
+    /// Get the balances of several users, fetching up to `concurrency` at a time
+    ///
+    /// Each balance is fetched with [`RainClient::get_user_balances`]. With
+    /// [`BulkMode::CollectAll`], a failure for one user, such as a 404 for a deleted
+    /// user, is returned alongside that user's id without affecting the others. With
+    /// [`BulkMode::FailFast`], the first failure stops the batch and the users not
+    /// fetched because of it are [`RainError::Cancelled`](crate::RainError::Cancelled).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_ids` - The users to fetch balances for
+    /// * `concurrency` - Maximum number of requests in flight (at least 1)
+    /// * `mode` - Whether to keep going after a failure
+    ///
+    /// # Returns
+    ///
+    /// Returns each user id paired with its result, in the same order as `user_ids`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::client::bulk::BulkMode;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_ids = [Uuid::new_v4(), Uuid::new_v4()];
+    /// let results = client
+    ///     .get_user_balances_bulk(&user_ids, 4, BulkMode::CollectAll)
+    ///     .await;
+    /// for (user_id, balances) in results {
+    ///     match balances {
+    ///         Ok(balances) => println!("{user_id}: {:?}", balances.spending_power),
+    ///         Err(e) => eprintln!("{user_id}: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_user_balances_bulk(
+        &self,
+        user_ids: &[Uuid],
+        concurrency: usize,
+        mode: BulkMode,
+    ) -> Vec<(Uuid, Result<BalanceResponse>)> {
+        let results = bulk::run(
+            user_ids
+                .iter()
+                .map(|user_id| self.get_user_balances(user_id)),
+            concurrency,
+            mode,
+        )
+        .await;
+        user_ids.iter().copied().zip(results).collect()
+    }
+
     // ============================================================================
     // Blocking Methods
     // ============================================================================
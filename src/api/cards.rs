@@ -2,9 +2,22 @@
 //!
 //! This module provides functionality to manage cards.
 
+#[cfg(feature = "async")]
+use crate::client::bulk::{self, BulkMode};
+use crate::client::wait::WaitConfig;
 use crate::client::RainClient;
 use crate::error::Result;
 use crate::models::cards::*;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::models::common::Amount;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::models::transactions::{
+    ListTransactionsParams, SpendTransactionStatus, Transaction, TransactionType,
+};
+#[cfg(any(feature = "async", feature = "sync"))]
+use chrono::{DateTime, TimeDelta, Utc};
+#[cfg(feature = "async")]
+use futures_util::TryStreamExt;
 use uuid::Uuid;
 
 impl RainClient {
@@ -52,33 +65,104 @@ impl RainClient {
     #[cfg(feature = "async")]
     pub async fn list_cards(&self, params: &ListCardsParams) -> Result<ListCardsResponse> {
         let mut path = "/cards".to_string();
-        let mut query_parts = Vec::new();
-
-        if let Some(ref company_id) = params.company_id {
-            query_parts.push(format!("companyId={company_id}"));
-        }
-        if let Some(ref user_id) = params.user_id {
-            query_parts.push(format!("userId={user_id}"));
-        }
-        if let Some(ref status) = params.status {
-            let status_str = serde_json::to_string(status)?;
-            query_parts.push(format!("status={}", status_str.trim_matches('"')));
-        }
-        if let Some(ref cursor) = params.cursor {
-            query_parts.push(format!("cursor={cursor}"));
-        }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
-
-        if !query_parts.is_empty() {
+        let query_string = params.to_query_string()?;
+        if !query_string.is_empty() {
             path.push('?');
-            path.push_str(&query_parts.join("&"));
+            path.push_str(&query_string);
         }
 
         self.get(&path).await
     }
 
+    /// Get a user's cards
+    ///
+    /// Shorthand for [`list_cards`](Self::list_cards) with `user_id` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user whose cards to list
+    /// * `status` - Only list cards with this status
+    /// * `cursor` - Cursor returned by a previous page
+    /// * `limit` - Page size
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::cards::CardStatus;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let cards = client
+    ///     .list_user_cards(&user_id, Some(CardStatus::Active), None, Some(20))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn list_user_cards(
+        &self,
+        user_id: &Uuid,
+        status: Option<CardStatus>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<ListCardsResponse> {
+        let params = ListCardsParams {
+            user_id: Some(*user_id),
+            ..owner_params(status, cursor, limit)
+        };
+        self.list_cards(&params).await
+    }
+
+    /// Get a company's cards
+    ///
+    /// Shorthand for [`list_cards`](Self::list_cards) with `company_id` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_id` - The company whose cards to list
+    /// * `status` - Only list cards with this status
+    /// * `cursor` - Cursor returned by a previous page
+    /// * `limit` - Page size
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let company_id = Uuid::new_v4();
+    /// let cards = client.list_company_cards(&company_id, None, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn list_company_cards(
+        &self,
+        company_id: &Uuid,
+        status: Option<CardStatus>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<ListCardsResponse> {
+        let params = ListCardsParams {
+            company_id: Some(*company_id),
+            ..owner_params(status, cursor, limit)
+        };
+        self.list_cards(&params).await
+    }
+
     /// Get a card by its ID
     ///
     /// # Arguments
@@ -119,6 +203,165 @@ impl RainClient {
         self.get(&path).await
     }
 
+    /// Wait for a card to be activated
+    ///
+    /// Polls [`get_card`](Self::get_card) every `wait.interval` until the card's
+    /// status is `active`. Physical cards start as `notActivated` until the user
+    /// activates them.
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    /// * `wait` - Polling interval and timeout
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Card`] once its status is `active`.
+    ///
+    /// # Errors
+    ///
+    /// - [`RainError::Timeout`](crate::error::RainError::Timeout) if the card is not
+    ///   active before `wait.timeout`
+    /// - [`RainError::Other`](crate::error::RainError::Other) if the card was canceled
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::client::wait::WaitConfig;
+    /// use std::time::Duration;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let wait = WaitConfig::new().with_timeout(Duration::from_secs(60));
+    /// let card = client.wait_for_card_active(&card_id, &wait).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn wait_for_card_active(&self, card_id: &Uuid, wait: &WaitConfig) -> Result<Card> {
+        crate::client::wait::poll_until(
+            wait,
+            &format!("Card {card_id}"),
+            || self.get_card(card_id),
+            activated,
+        )
+        .await
+    }
+
+    /// Get several cards by id, fetching up to `concurrency` at a time
+    ///
+    /// Each card is fetched with [`RainClient::get_card`]. With
+    /// [`BulkMode::CollectAll`], a failure for one card, such as a 404, is returned in
+    /// its slot without affecting the others. With [`BulkMode::FailFast`], the first
+    /// failure stops the batch and the cards not fetched because of it are
+    /// [`RainError::Cancelled`](crate::RainError::Cancelled).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The card ids to fetch
+    /// * `concurrency` - Maximum number of requests in flight (at least 1)
+    /// * `mode` - Whether to keep going after a failure
+    ///
+    /// # Returns
+    ///
+    /// Returns one result per id, in the same order as `ids`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::client::bulk::BulkMode;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let ids = [Uuid::new_v4(), Uuid::new_v4()];
+    /// for card in client.get_cards(&ids, 4, BulkMode::CollectAll).await {
+    ///     match card {
+    ///         Ok(card) => println!("{}: {}", card.id, card.last4),
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_cards(
+        &self,
+        ids: &[Uuid],
+        concurrency: usize,
+        mode: BulkMode,
+    ) -> Vec<Result<Card>> {
+        bulk::run(ids.iter().map(|id| self.get_card(id)), concurrency, mode).await
+    }
+
+    /// Get how much of a card's limit has been spent this period
+    ///
+    /// The API does not report this, so it is estimated from the card's limit and
+    /// its spend transactions. The estimate assumes limits apply over rolling
+    /// windows ending now: 24 hours, 7, 30 or 365 days depending on the frequency.
+    /// Rain does not document how its windows are aligned, so the figures can
+    /// differ from what the processor enforces, especially near the edge of a
+    /// window. Pending and completed spends authorized in the window count against
+    /// the limit; declined and reversed ones do not. Cards without a limit, or with
+    /// an all-time or per-authorization limit, count every spend, and a
+    /// per-authorization limit always has its full amount remaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`CardSpendSummary`] with the limit, amount spent, amount remaining
+    /// and when the oldest counted spend leaves the window.
+    ///
+    /// # Errors
+    ///
+    /// This method can return the following errors:
+    /// - `401` - Invalid authorization
+    /// - `404` - Card not found
+    /// - `500` - Internal server error
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let summary = client.get_card_spend_summary(&card_id).await?;
+    /// println!("Spent {:?}, {:?} left", summary.spent, summary.remaining);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_card_spend_summary(&self, card_id: &Uuid) -> Result<CardSpendSummary> {
+        let card = self.get_card(card_id).await?;
+        let window = limit_window(card.limit.as_ref());
+        let params = card_spend_params(card_id, window, self.now());
+        let transactions: Vec<Transaction> =
+            self.list_all_transactions(&params).try_collect().await?;
+        Ok(spend_summary(card.limit, window, &transactions))
+    }
+
     /// Update a card
     ///
     /// # Arguments
@@ -168,6 +411,218 @@ impl RainClient {
         self.patch(&path, request).await
     }
 
+    /// Update a card only if it has not changed since `etag` was read
+    ///
+    /// Sends `If-Match: <etag>` so a concurrent modification is rejected instead of
+    /// silently overwritten. Read the ETag with [`RainClient::get_with_etag`].
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    /// * `request` - The update request
+    /// * `etag` - The ETag returned when the card was read
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated [`Card`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::PreconditionFailed`](crate::RainError::PreconditionFailed)
+    /// if the card changed since `etag` was read (HTTP 412), along with the errors
+    /// of [`RainClient::update_card`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::cards::{Card, CardStatus, UpdateCardRequest};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let (card, etag) = client.get_with_etag::<Card>(&format!("/cards/{card_id}")).await?;
+    /// let request = UpdateCardRequest {
+    ///     status: Some(CardStatus::Locked),
+    ///     limit: None,
+    ///     billing: None,
+    ///     configuration: None,
+    /// };
+    /// if let Some(etag) = etag {
+    ///     let card = client.update_card_if_match(&card.id, &request, &etag).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn update_card_if_match(
+        &self,
+        card_id: &Uuid,
+        request: &UpdateCardRequest,
+        etag: &str,
+    ) -> Result<Card> {
+        let path = format!("/cards/{card_id}");
+        self.patch_with_headers(&path, request, vec![("If-Match", etag)])
+            .await
+    }
+
+    /// Rename a card, leaving its status, limit, billing and art unchanged
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    /// * `name` - The new display name
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated [`Card`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let card = client.set_card_display_name(&card_id, "Travel").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn set_card_display_name(&self, card_id: &Uuid, name: &str) -> Result<Card> {
+        let request = configuration_request(CardConfiguration {
+            display_name: Some(name.to_string()),
+            ..CardConfiguration::default()
+        });
+        self.update_card(card_id, &request).await
+    }
+
+    /// Change a virtual card's art, leaving every other setting unchanged
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    /// * `art_url` - URL of the new card art
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated [`Card`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let card = client.set_card_art(&card_id, "https://example.com/art.png").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn set_card_art(&self, card_id: &Uuid, art_url: &str) -> Result<Card> {
+        let request = configuration_request(CardConfiguration {
+            virtual_card_art: Some(art_url.to_string()),
+            ..CardConfiguration::default()
+        });
+        self.update_card(card_id, &request).await
+    }
+
+    /// Set a card's spending limit, leaving every other setting unchanged
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    /// * `amount` - The limit in cents
+    /// * `frequency` - The period the limit applies to
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated [`Card`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::cards::LimitFrequency;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let card = client
+    ///     .set_card_limit(&card_id, 50_000, LimitFrequency::Per30DayPeriod)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn set_card_limit(
+        &self,
+        card_id: &Uuid,
+        amount: i64,
+        frequency: LimitFrequency,
+    ) -> Result<Card> {
+        let request = limit_request(Some(CardLimit { amount, frequency }));
+        self.update_card(card_id, &request).await
+    }
+
+    /// Remove a card's spending limit, leaving every other setting unchanged
+    ///
+    /// Sends `"limit": null` to clear the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated [`Card`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let card = client.remove_card_limit(&card_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn remove_card_limit(&self, card_id: &Uuid) -> Result<Card> {
+        let request = limit_request(None);
+        self.update_card(card_id, &request).await
+    }
+
     /// Get a card's encrypted data (PAN and CVC)
     ///
     /// # Arguments
@@ -204,6 +659,87 @@ impl RainClient {
             .await
     }
 
+    /// Create a virtual card for a user and fetch its encrypted data
+    ///
+    /// Issues the card with [`create_user_card`](Self::create_user_card), then reads
+    /// its secrets with [`get_card_secrets`](Self::get_card_secrets). Secrets of a
+    /// freshly issued card may not be available straight away, so a 404 is retried a
+    /// few times with a short delay before giving up. Each retry draws on the
+    /// client's [retry budget](RainClient::retry_budget), and the 404 is returned
+    /// without retrying once the budget is exhausted. The returned secrets are still
+    /// encrypted with the session key.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `request` - The card creation request
+    /// * `session_id` - The encrypted session ID
+    ///
+    /// # Returns
+    ///
+    /// Returns the created [`Card`] and its [`CardSecrets`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the creation error if the card could not be issued, or the last
+    /// secrets error once the retries or the retry budget are used up. The card
+    /// exists in the latter
+    /// case, so fetch its secrets again rather than creating another one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::cards::{CardType, CreateCardRequest};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let request = CreateCardRequest {
+    ///     r#type: CardType::Virtual,
+    ///     status: None,
+    ///     limit: None,
+    ///     configuration: None,
+    ///     shipping: None,
+    ///     bulk_shipping_group_id: None,
+    ///     billing: None,
+    /// };
+    /// let (card, secrets) = client
+    ///     .create_virtual_card_with_secrets(&user_id, &request, "your-session-id")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn create_virtual_card_with_secrets(
+        &self,
+        user_id: &Uuid,
+        request: &CreateCardRequest,
+        session_id: &str,
+    ) -> Result<(Card, CardSecrets)> {
+        let card = self.create_user_card(user_id, request).await?;
+        let mut attempts = 1;
+        loop {
+            self.retry_budget().deposit();
+            match self.get_card_secrets(&card.id, session_id).await {
+                Err(err)
+                    if attempts < SECRETS_ATTEMPTS
+                        && secrets_pending(&err)
+                        && self.retry_budget().try_withdraw() =>
+                {
+                    attempts += 1;
+                    tokio::time::sleep(SECRETS_RETRY_DELAY).await;
+                }
+                result => return result.map(|secrets| (card, secrets)),
+            }
+        }
+    }
+
     /// Get processor details of a card
     ///
     /// # Arguments
@@ -369,10 +905,77 @@ impl RainClient {
         user_id: &Uuid,
         request: &CreateCardRequest,
     ) -> Result<Card> {
+        #[cfg(feature = "validate-schema")]
+        crate::schema::check(crate::schema::Schema::CreateCardRequest, request)?;
         let path = format!("/users/{user_id}/cards");
         self.post(&path, request).await
     }
 
+    /// Replace a lost, stolen or damaged card
+    ///
+    /// Creates a replacement of the same type, limit and configuration for the same
+    /// user, then cancels the old card. For [`ReissueReason::Lost`] and
+    /// [`ReissueReason::Stolen`] the old card is locked first, so it cannot be used
+    /// while the replacement is created; for [`ReissueReason::Damaged`] it stays
+    /// usable until then. The old card is only canceled once the replacement
+    /// exists, so a failed creation never leaves the user without a card.
+    ///
+    /// # Arguments
+    ///
+    /// * `card_id` - The unique identifier of the card to replace
+    /// * `reason` - Why the card is being replaced
+    /// * `shipping` - Where to ship a physical replacement
+    ///
+    /// # Returns
+    ///
+    /// Returns the new [`Card`].
+    ///
+    /// # Errors
+    ///
+    /// If the replacement cannot be created, the error is returned and a
+    /// compromised card is left locked; unlock it with
+    /// [`update_card`](Self::update_card) if needed. If canceling the old card
+    /// fails, the replacement already exists: find it with
+    /// [`list_user_cards`](Self::list_user_cards) and cancel the old card again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::cards::ReissueReason;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let card_id = Uuid::new_v4();
+    /// let card = client.reissue_card(&card_id, ReissueReason::Stolen, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn reissue_card(
+        &self,
+        card_id: &Uuid,
+        reason: ReissueReason,
+        shipping: Option<ShippingAddress>,
+    ) -> Result<Card> {
+        let card = self.get_card(card_id).await?;
+        let replacement = replacement_request(&card, shipping);
+
+        if reason.is_compromised() {
+            self.update_card(card_id, &status_request(CardStatus::Locked))
+                .await?;
+        }
+        let new_card = self.create_user_card(&card.user_id, &replacement).await?;
+        self.update_card(card_id, &status_request(CardStatus::Canceled))
+            .await?;
+        Ok(new_card)
+    }
+
     // ============================================================================
     // Blocking Methods
     // ============================================================================
@@ -381,33 +984,47 @@ impl RainClient {
     #[cfg(feature = "sync")]
     pub fn list_cards_blocking(&self, params: &ListCardsParams) -> Result<Vec<Card>> {
         let mut path = "/cards".to_string();
-        let mut query_parts = Vec::new();
-
-        if let Some(ref company_id) = params.company_id {
-            query_parts.push(format!("companyId={company_id}"));
-        }
-        if let Some(ref user_id) = params.user_id {
-            query_parts.push(format!("userId={user_id}"));
-        }
-        if let Some(ref status) = params.status {
-            let status_str = serde_json::to_string(status)?;
-            query_parts.push(format!("status={}", status_str.trim_matches('"')));
-        }
-        if let Some(ref cursor) = params.cursor {
-            query_parts.push(format!("cursor={cursor}"));
-        }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
-
-        if !query_parts.is_empty() {
+        let query_string = params.to_query_string()?;
+        if !query_string.is_empty() {
             path.push('?');
-            path.push_str(&query_parts.join("&"));
+            path.push_str(&query_string);
         }
 
         self.get_blocking(&path)
     }
 
+    /// Get a user's cards (blocking)
+    #[cfg(feature = "sync")]
+    pub fn list_user_cards_blocking(
+        &self,
+        user_id: &Uuid,
+        status: Option<CardStatus>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<ListCardsResponse> {
+        let params = ListCardsParams {
+            user_id: Some(*user_id),
+            ..owner_params(status, cursor, limit)
+        };
+        self.list_cards_blocking(&params)
+    }
+
+    /// Get a company's cards (blocking)
+    #[cfg(feature = "sync")]
+    pub fn list_company_cards_blocking(
+        &self,
+        company_id: &Uuid,
+        status: Option<CardStatus>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<ListCardsResponse> {
+        let params = ListCardsParams {
+            company_id: Some(*company_id),
+            ..owner_params(status, cursor, limit)
+        };
+        self.list_cards_blocking(&params)
+    }
+
     /// Get a card by its ID (blocking)
     #[cfg(feature = "sync")]
     pub fn get_card_blocking(&self, card_id: &Uuid) -> Result<Card> {
@@ -415,6 +1032,27 @@ impl RainClient {
         self.get_blocking(&path)
     }
 
+    /// Wait for a card to be activated (blocking)
+    #[cfg(feature = "sync")]
+    pub fn wait_for_card_active_blocking(&self, card_id: &Uuid, wait: &WaitConfig) -> Result<Card> {
+        crate::client::wait::poll_until_blocking(
+            wait,
+            &format!("Card {card_id}"),
+            || self.get_card_blocking(card_id),
+            activated,
+        )
+    }
+
+    /// Get how much of a card's limit has been spent this period (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_card_spend_summary_blocking(&self, card_id: &Uuid) -> Result<CardSpendSummary> {
+        let card = self.get_card_blocking(card_id)?;
+        let window = limit_window(card.limit.as_ref());
+        let params = card_spend_params(card_id, window, self.now());
+        let transactions = self.list_all_transactions_blocking(&params)?;
+        Ok(spend_summary(card.limit, window, &transactions))
+    }
+
     /// Update a card (blocking)
     #[cfg(feature = "sync")]
     pub fn update_card_blocking(
@@ -426,6 +1064,57 @@ impl RainClient {
         self.patch_blocking(&path, request)
     }
 
+    /// Update a card only if it has not changed since `etag` was read (blocking)
+    #[cfg(feature = "sync")]
+    pub fn update_card_if_match_blocking(
+        &self,
+        card_id: &Uuid,
+        request: &UpdateCardRequest,
+        etag: &str,
+    ) -> Result<Card> {
+        let path = format!("/cards/{card_id}");
+        self.patch_with_headers_blocking(&path, request, vec![("If-Match", etag)])
+    }
+
+    /// Rename a card, leaving its other settings unchanged (blocking)
+    #[cfg(feature = "sync")]
+    pub fn set_card_display_name_blocking(&self, card_id: &Uuid, name: &str) -> Result<Card> {
+        let request = configuration_request(CardConfiguration {
+            display_name: Some(name.to_string()),
+            ..CardConfiguration::default()
+        });
+        self.update_card_blocking(card_id, &request)
+    }
+
+    /// Change a virtual card's art, leaving its other settings unchanged (blocking)
+    #[cfg(feature = "sync")]
+    pub fn set_card_art_blocking(&self, card_id: &Uuid, art_url: &str) -> Result<Card> {
+        let request = configuration_request(CardConfiguration {
+            virtual_card_art: Some(art_url.to_string()),
+            ..CardConfiguration::default()
+        });
+        self.update_card_blocking(card_id, &request)
+    }
+
+    /// Set a card's spending limit, leaving its other settings unchanged (blocking)
+    #[cfg(feature = "sync")]
+    pub fn set_card_limit_blocking(
+        &self,
+        card_id: &Uuid,
+        amount: i64,
+        frequency: LimitFrequency,
+    ) -> Result<Card> {
+        let request = limit_request(Some(CardLimit { amount, frequency }));
+        self.update_card_blocking(card_id, &request)
+    }
+
+    /// Remove a card's spending limit, leaving its other settings unchanged (blocking)
+    #[cfg(feature = "sync")]
+    pub fn remove_card_limit_blocking(&self, card_id: &Uuid) -> Result<Card> {
+        let request = limit_request(None);
+        self.update_card_blocking(card_id, &request)
+    }
+
     /// Create a card for a user (blocking)
     #[cfg(feature = "sync")]
     pub fn create_user_card_blocking(
@@ -433,7 +1122,223 @@ impl RainClient {
         user_id: &Uuid,
         request: &CreateCardRequest,
     ) -> Result<Card> {
+        #[cfg(feature = "validate-schema")]
+        crate::schema::check(crate::schema::Schema::CreateCardRequest, request)?;
         let path = format!("/users/{user_id}/cards");
         self.post_blocking(&path, request)
     }
+
+    /// Get encrypted card data (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_card_secrets_blocking(
+        &self,
+        card_id: &Uuid,
+        session_id: &str,
+    ) -> Result<CardSecrets> {
+        let path = format!("/cards/{card_id}/secrets");
+        self.get_with_headers_blocking(&path, vec![("SessionId", session_id)])
+    }
+
+    /// Create a virtual card for a user and fetch its encrypted data (blocking)
+    #[cfg(feature = "sync")]
+    pub fn create_virtual_card_with_secrets_blocking(
+        &self,
+        user_id: &Uuid,
+        request: &CreateCardRequest,
+        session_id: &str,
+    ) -> Result<(Card, CardSecrets)> {
+        let card = self.create_user_card_blocking(user_id, request)?;
+        let mut attempts = 1;
+        loop {
+            self.retry_budget().deposit();
+            match self.get_card_secrets_blocking(&card.id, session_id) {
+                Err(err)
+                    if attempts < SECRETS_ATTEMPTS
+                        && secrets_pending(&err)
+                        && self.retry_budget().try_withdraw() =>
+                {
+                    attempts += 1;
+                    std::thread::sleep(SECRETS_RETRY_DELAY);
+                }
+                result => return result.map(|secrets| (card, secrets)),
+            }
+        }
+    }
+
+    /// Replace a lost, stolen or damaged card (blocking)
+    #[cfg(feature = "sync")]
+    pub fn reissue_card_blocking(
+        &self,
+        card_id: &Uuid,
+        reason: ReissueReason,
+        shipping: Option<ShippingAddress>,
+    ) -> Result<Card> {
+        let card = self.get_card_blocking(card_id)?;
+        let replacement = replacement_request(&card, shipping);
+
+        if reason.is_compromised() {
+            self.update_card_blocking(card_id, &status_request(CardStatus::Locked))?;
+        }
+        let new_card = self.create_user_card_blocking(&card.user_id, &replacement)?;
+        self.update_card_blocking(card_id, &status_request(CardStatus::Canceled))?;
+        Ok(new_card)
+    }
+}
+
+/// Length of the rolling window a card's limit is estimated over, or `None` for all time
+#[cfg(any(feature = "async", feature = "sync"))]
+fn limit_window(limit: Option<&CardLimit>) -> Option<TimeDelta> {
+    match limit?.frequency {
+        LimitFrequency::Per24HourPeriod => Some(TimeDelta::hours(24)),
+        LimitFrequency::Per7DayPeriod => Some(TimeDelta::days(7)),
+        LimitFrequency::Per30DayPeriod => Some(TimeDelta::days(30)),
+        LimitFrequency::PerYearPeriod => Some(TimeDelta::days(365)),
+        LimitFrequency::AllTime | LimitFrequency::PerAuthorization => None,
+    }
+}
+
+/// Query listing a card's spends authorized in the `window` ending at `now`
+#[cfg(any(feature = "async", feature = "sync"))]
+fn card_spend_params(
+    card_id: &Uuid,
+    window: Option<TimeDelta>,
+    now: DateTime<Utc>,
+) -> ListTransactionsParams {
+    ListTransactionsParams {
+        card_id: Some(*card_id),
+        transaction_type: Some(vec![TransactionType::Spend]),
+        authorized_after: window.map(|window| now - window),
+        ..Default::default()
+    }
+}
+
+/// Sum the spends counting against a limit and work out what remains
+#[cfg(any(feature = "async", feature = "sync"))]
+fn spend_summary(
+    limit: Option<CardLimit>,
+    window: Option<TimeDelta>,
+    transactions: &[Transaction],
+) -> CardSpendSummary {
+    let counted: Vec<_> = transactions
+        .iter()
+        .filter_map(|transaction| match transaction {
+            Transaction::Spend { spend, .. }
+                if matches!(
+                    spend.status,
+                    SpendTransactionStatus::Pending | SpendTransactionStatus::Completed
+                ) =>
+            {
+                Some(spend)
+            }
+            _ => None,
+        })
+        .collect();
+    let spent: Amount = counted.iter().map(|spend| spend.amount).sum();
+    let remaining = limit.as_ref().map(|limit| match limit.frequency {
+        LimitFrequency::PerAuthorization => Amount::from(limit.amount),
+        _ => (Amount::from(limit.amount) - spent).max(Amount::from(0)),
+    });
+    let period_end = window.and_then(|window| {
+        let oldest = counted.iter().map(|spend| spend.authorized_at).min()?;
+        Some(oldest + window)
+    });
+    CardSpendSummary {
+        limit,
+        spent,
+        remaining,
+        period_end,
+    }
+}
+
+/// Check whether a polled card is active, failing once it is canceled
+#[cfg(any(feature = "async", feature = "sync"))]
+fn activated(card: &Card) -> Result<bool> {
+    match card.status {
+        CardStatus::Active => Ok(true),
+        CardStatus::Canceled => Err(crate::error::RainError::Other(anyhow::anyhow!(
+            "Card {} was canceled before it was activated",
+            card.id
+        ))),
+        _ => Ok(false),
+    }
+}
+
+/// Request that changes only the status of a card
+#[cfg(any(feature = "async", feature = "sync"))]
+fn status_request(status: CardStatus) -> UpdateCardRequest {
+    UpdateCardRequest {
+        status: Some(status),
+        limit: None,
+        billing: None,
+        configuration: None,
+    }
+}
+
+/// Request that changes only the given configuration fields of a card
+#[cfg(any(feature = "async", feature = "sync"))]
+fn configuration_request(configuration: CardConfiguration) -> UpdateCardRequest {
+    UpdateCardRequest {
+        status: None,
+        limit: None,
+        billing: None,
+        configuration: Some(configuration),
+    }
+}
+
+/// Request that sets or, with `None`, clears only the limit of a card
+#[cfg(any(feature = "async", feature = "sync"))]
+fn limit_request(limit: Option<CardLimit>) -> UpdateCardRequest {
+    UpdateCardRequest {
+        status: None,
+        limit: Some(limit),
+        billing: None,
+        configuration: None,
+    }
+}
+
+/// Request for a card with the same type and limit as an existing one
+#[cfg(any(feature = "async", feature = "sync"))]
+fn replacement_request(card: &Card, shipping: Option<ShippingAddress>) -> CreateCardRequest {
+    CreateCardRequest {
+        r#type: card.r#type.clone(),
+        status: None,
+        limit: card.limit.clone(),
+        configuration: card.configuration.clone(),
+        shipping,
+        bulk_shipping_group_id: None,
+        billing: None,
+    }
+}
+
+/// List parameters shared by the per-user and per-company card queries
+#[cfg(any(feature = "async", feature = "sync"))]
+fn owner_params(
+    status: Option<CardStatus>,
+    cursor: Option<&str>,
+    limit: Option<u32>,
+) -> ListCardsParams {
+    ListCardsParams {
+        status: status.map(|status| vec![status]),
+        cursor: cursor.map(str::to_string),
+        limit,
+        ..ListCardsParams::default()
+    }
+}
+
+/// Number of times the secrets of a new card are requested before giving up
+#[cfg(any(feature = "async", feature = "sync"))]
+const SECRETS_ATTEMPTS: u32 = 3;
+
+/// Delay between requests for the secrets of a new card
+#[cfg(any(feature = "async", feature = "sync"))]
+const SECRETS_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Whether a secrets error means the card's secrets are not available yet
+#[cfg(any(feature = "async", feature = "sync"))]
+fn secrets_pending(err: &crate::error::RainError) -> bool {
+    matches!(
+        err,
+        crate::error::RainError::ApiError { status: 404, .. }
+            | crate::error::RainError::NotFound(_)
+    )
 }
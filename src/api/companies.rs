@@ -5,6 +5,7 @@
 use crate::client::RainClient;
 use crate::error::Result;
 use crate::models::charges::*;
+use crate::models::common::page_limit;
 use crate::models::companies::*;
 use uuid::Uuid;
 
@@ -53,9 +54,7 @@ impl RainClient {
         if let Some(ref cursor) = params.cursor {
             query_parts.push(format!("cursor={cursor}"));
         }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
+        query_parts.push(format!("limit={}", page_limit(params.limit)));
 
         if !query_parts.is_empty() {
             path.push('?');
@@ -156,6 +155,27 @@ impl RainClient {
         self.patch(&path, request).await
     }
 
+    /// Update a company only if it has not changed since `etag` was read
+    ///
+    /// Sends `If-Match: <etag>`; read the ETag with [`RainClient::get_with_etag`].
+    /// See [`RainClient::update_card_if_match`] for a full example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::PreconditionFailed`](crate::RainError::PreconditionFailed)
+    /// if the company changed since `etag` was read (HTTP 412).
+    #[cfg(feature = "async")]
+    pub async fn update_company_if_match(
+        &self,
+        company_id: &Uuid,
+        request: &UpdateCompanyRequest,
+        etag: &str,
+    ) -> Result<Company> {
+        let path = format!("/companies/{company_id}");
+        self.patch_with_headers(&path, request, vec![("If-Match", etag)])
+            .await
+    }
+
     /// Charge a company a custom fee
     ///
     /// # Arguments
@@ -192,6 +212,8 @@ impl RainClient {
     /// let request = CreateChargeRequest {
     ///     amount: 1000, // $10.00 in cents
     ///     description: "Custom fee".to_string(),
+    ///     currency: None,
+    ///     metadata: None,
     /// };
     /// let charge = client.charge_company(&company_id, &request).await?;
     /// # Ok(())
@@ -279,9 +301,7 @@ impl RainClient {
         if let Some(ref cursor) = params.cursor {
             query_parts.push(format!("cursor={cursor}"));
         }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
+        query_parts.push(format!("limit={}", page_limit(params.limit)));
 
         if !query_parts.is_empty() {
             path.push('?');
@@ -309,6 +329,18 @@ impl RainClient {
         self.patch_blocking(&path, request)
     }
 
+    /// Update a company only if it has not changed since `etag` was read (blocking)
+    #[cfg(feature = "sync")]
+    pub fn update_company_if_match_blocking(
+        &self,
+        company_id: &Uuid,
+        request: &UpdateCompanyRequest,
+        etag: &str,
+    ) -> Result<Company> {
+        let path = format!("/companies/{company_id}");
+        self.patch_with_headers_blocking(&path, request, vec![("If-Match", etag)])
+    }
+
     /// Charge a company a custom fee (blocking)
     #[cfg(feature = "sync")]
     pub fn charge_company_blocking(
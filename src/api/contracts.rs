@@ -48,6 +48,57 @@ impl RainClient {
         self.get(&path).await
     }
 
+    /// Get a company's smart contract on one chain
+    ///
+    /// Fetches every contract of the company with
+    /// [`get_company_contracts`](Self::get_company_contracts) and keeps the one on
+    /// `chain_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_id` - The unique identifier of the company
+    /// * `chain_id` - The id of the chain, e.g. `137` for Polygon
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Contract`] on the chain, or `None` if the company has none there.
+    ///
+    /// # Errors
+    ///
+    /// This method can return the following errors:
+    /// - `401` - Invalid authorization
+    /// - `404` - Company not found
+    /// - `500` - Internal server error
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let company_id = Uuid::new_v4();
+    /// if let Some(contract) = client.get_company_contract_on_chain(&company_id, 137).await? {
+    ///     println!("Deposit to {}", contract.deposit_address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_company_contract_on_chain(
+        &self,
+        company_id: &Uuid,
+        chain_id: i64,
+    ) -> Result<Option<Contract>> {
+        let contracts = self.get_company_contracts(company_id).await?;
+        Ok(contracts_for_chain(&contracts, chain_id).cloned())
+    }
+
     /// Create a smart contract for a company
     ///
     /// # Arguments
@@ -290,6 +341,17 @@ impl RainClient {
         self.get_blocking(&path)
     }
 
+    /// Get a company's smart contract on one chain (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_company_contract_on_chain_blocking(
+        &self,
+        company_id: &Uuid,
+        chain_id: i64,
+    ) -> Result<Option<Contract>> {
+        let contracts = self.get_company_contracts_blocking(company_id)?;
+        Ok(contracts_for_chain(&contracts, chain_id).cloned())
+    }
+
     /// Create a smart contract for a company (blocking)
     #[cfg(feature = "sync")]
     pub fn create_company_contract_blocking(
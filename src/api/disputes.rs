@@ -2,9 +2,13 @@
 //!
 //! This module provides functionality to manage disputes.
 
-use crate::client::RainClient;
-use crate::error::Result;
+use crate::client::{encode_query, RainClient};
+use crate::error::{ApiErrorResponse, ConflictReason, RainError, Result};
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::models::common::page_limit;
 use crate::models::disputes::*;
+use serde::de::IgnoredAny;
+use std::path::Path;
 use uuid::Uuid;
 
 impl RainClient {
@@ -26,7 +30,7 @@ impl RainClient {
     #[cfg(feature = "async")]
     pub async fn list_disputes(&self, params: &ListDisputesParams) -> Result<Vec<Dispute>> {
         let path = "/disputes";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -35,6 +39,101 @@ impl RainClient {
         self.get(&full_path).await
     }
 
+    /// Get every dispute matching the filters, following pagination
+    ///
+    /// Pages are fetched one after another, using the id of the last dispute in
+    /// each page as the cursor for the next. A page shorter than the page size,
+    /// `params.limit` or [`DEFAULT_PAGE_SIZE`](crate::models::common::DEFAULT_PAGE_SIZE)
+    /// if unset, is the last. At most
+    /// [`MAX_DISPUTE_PAGES`] pages are fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters to filter disputes; `limit` sets the page size
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`Vec<Dispute>`] with the disputes of every page, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from a page, or
+    /// [`RainError::Other`](crate::error::RainError::Other) if there are more than
+    /// [`MAX_DISPUTE_PAGES`] pages; narrow the filters in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::disputes::ListDisputesParams;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let params = ListDisputesParams {
+    ///     user_id: Some(Uuid::new_v4()),
+    ///     ..Default::default()
+    /// };
+    /// let disputes = client.all_disputes(&params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn all_disputes(&self, params: &ListDisputesParams) -> Result<Vec<Dispute>> {
+        let mut disputes = Vec::new();
+        let mut next = Some(params.clone());
+        for _ in 0..MAX_DISPUTE_PAGES {
+            let Some(params) = next.take() else {
+                return Ok(disputes);
+            };
+            let page = self.list_disputes(&params).await?;
+            next = next_disputes_page(params, &page);
+            disputes.extend(page);
+        }
+        match next {
+            None => Ok(disputes),
+            Some(_) => Err(too_many_dispute_pages()),
+        }
+    }
+
+    /// Get the disputes raised by a user
+    ///
+    /// Shorthand for [`list_disputes`](Self::list_disputes) with `user_id` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user whose disputes to list
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let disputes = client.list_user_disputes(&user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn list_user_disputes(&self, user_id: &Uuid) -> Result<Vec<Dispute>> {
+        let params = ListDisputesParams {
+            user_id: Some(*user_id),
+            ..Default::default()
+        };
+        self.list_disputes(&params).await
+    }
+
     /// Get a dispute by its id
     ///
     /// # Arguments
@@ -86,6 +185,54 @@ impl RainClient {
         Ok(())
     }
 
+    /// Update a dispute, unless it can no longer be edited
+    ///
+    /// Checks [`Dispute::is_editable`] on the given, freshly fetched dispute and
+    /// fails without sending a request if it is resolved or canceled.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute` - The dispute to update
+    /// * `request` - The update request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::Conflict`] with [`ConflictReason::DisputeNotEditable`]
+    /// if the dispute is not editable, otherwise the errors of
+    /// [`update_dispute`](Self::update_dispute).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::disputes::UpdateDisputeRequest;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let dispute = client.get_dispute(&Uuid::new_v4()).await?;
+    /// let request = UpdateDisputeRequest {
+    ///     status: None,
+    ///     text_evidence: Some("Tracking shows no delivery".to_string()),
+    /// };
+    /// client.update_dispute_if_editable(&dispute, &request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn update_dispute_if_editable(
+        &self,
+        dispute: &Dispute,
+        request: &UpdateDisputeRequest,
+    ) -> Result<()> {
+        ensure_editable(dispute)?;
+        self.update_dispute(&dispute.id, request).await
+    }
+
     /// Get a dispute's file evidence
     ///
     /// # Arguments
@@ -101,6 +248,45 @@ impl RainClient {
         self.get_bytes(&path).await
     }
 
+    /// Get a dispute's file evidence with its content type
+    ///
+    /// Use the content type to serve the file back with the right headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute_id` - The unique identifier of the dispute
+    ///
+    /// # Returns
+    ///
+    /// Returns the file evidence as raw bytes, and the response's `Content-Type`
+    /// if it had one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let dispute_id = Uuid::new_v4();
+    /// let (bytes, content_type) = client.get_dispute_evidence_with_type(&dispute_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_dispute_evidence_with_type(
+        &self,
+        dispute_id: &Uuid,
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        let path = format!("/disputes/{dispute_id}/evidence");
+        self.get_bytes_with_type(&path).await
+    }
+
     /// Upload a file as evidence for a dispute
     ///
     /// # Arguments
@@ -119,16 +305,98 @@ impl RainClient {
     ) -> Result<()> {
         let path = format!("/disputes/{dispute_id}/evidence");
 
-        use reqwest::multipart::{Form, Part};
-        let form = Form::new()
-            .text("name", request.name.clone())
-            .text("type", request.evidence_type.clone())
-            .part(
-                "evidence",
-                Part::bytes(request.file.clone()).file_name(request.name.clone()),
-            );
+        let file =
+            reqwest::multipart::Part::bytes(request.file.clone()).file_name(request.name.clone());
+        let fields = [
+            ("name", request.name.clone()),
+            ("type", request.evidence_type.as_str().to_string()),
+        ];
+
+        self.put_multipart_with_fields::<IgnoredAny>(&path, "evidence", file, &fields)
+            .await?;
+        Ok(())
+    }
+
+    /// Upload a file as evidence for a dispute, unless it can no longer be edited
+    ///
+    /// Checks [`Dispute::is_editable`] on the given, freshly fetched dispute and
+    /// fails without sending a request if it is resolved or canceled.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute` - The dispute to add evidence to
+    /// * `request` - The evidence upload request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::Conflict`] with [`ConflictReason::DisputeNotEditable`]
+    /// if the dispute is not editable, otherwise the errors of
+    /// [`upload_dispute_evidence`](Self::upload_dispute_evidence).
+    #[cfg(feature = "async")]
+    pub async fn upload_dispute_evidence_if_editable(
+        &self,
+        dispute: &Dispute,
+        request: &UploadDisputeEvidenceRequest,
+    ) -> Result<()> {
+        ensure_editable(dispute)?;
+        self.upload_dispute_evidence(&dispute.id, request).await
+    }
 
-        self.put_multipart_no_content(&path, form).await
+    /// Upload a file from disk as evidence for a dispute
+    ///
+    /// Reads the file and uses its file name as the evidence name, mirroring the
+    /// path-based document upload methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute_id` - The unique identifier of the dispute
+    /// * `path` - Path to the evidence file
+    /// * `evidence_type` - The type of evidence being uploaded
+    ///
+    /// # Returns
+    ///
+    /// Returns success (204 No Content) with no response body.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::disputes::EvidenceType;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let dispute_id = Uuid::new_v4();
+    /// client
+    ///     .upload_dispute_evidence_from_path(&dispute_id, "receipt.pdf", EvidenceType::Receipt)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn upload_dispute_evidence_from_path(
+        &self,
+        dispute_id: &Uuid,
+        path: impl AsRef<Path>,
+        evidence_type: EvidenceType,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let file = tokio::fs::read(path).await.map_err(|e| {
+            RainError::Other(anyhow::anyhow!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let request = UploadDisputeEvidenceRequest {
+            name: evidence_file_name(path),
+            evidence_type,
+            file,
+        };
+        self.upload_dispute_evidence(dispute_id, &request).await
     }
 
     /// Create a dispute for a transaction
@@ -167,7 +435,7 @@ impl RainClient {
     #[cfg(feature = "sync")]
     pub fn list_disputes_blocking(&self, params: &ListDisputesParams) -> Result<Vec<Dispute>> {
         let path = "/disputes";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -176,6 +444,35 @@ impl RainClient {
         self.get_blocking(&full_path)
     }
 
+    /// Get every dispute matching the filters, following pagination (blocking)
+    #[cfg(feature = "sync")]
+    pub fn all_disputes_blocking(&self, params: &ListDisputesParams) -> Result<Vec<Dispute>> {
+        let mut disputes = Vec::new();
+        let mut next = Some(params.clone());
+        for _ in 0..MAX_DISPUTE_PAGES {
+            let Some(params) = next.take() else {
+                return Ok(disputes);
+            };
+            let page = self.list_disputes_blocking(&params)?;
+            next = next_disputes_page(params, &page);
+            disputes.extend(page);
+        }
+        match next {
+            None => Ok(disputes),
+            Some(_) => Err(too_many_dispute_pages()),
+        }
+    }
+
+    /// Get the disputes raised by a user (blocking)
+    #[cfg(feature = "sync")]
+    pub fn list_user_disputes_blocking(&self, user_id: &Uuid) -> Result<Vec<Dispute>> {
+        let params = ListDisputesParams {
+            user_id: Some(*user_id),
+            ..Default::default()
+        };
+        self.list_disputes_blocking(&params)
+    }
+
     /// Get a dispute by its id (blocking)
     #[cfg(feature = "sync")]
     pub fn get_dispute_blocking(&self, dispute_id: &Uuid) -> Result<Dispute> {
@@ -195,6 +492,17 @@ impl RainClient {
         Ok(())
     }
 
+    /// Update a dispute, unless it can no longer be edited (blocking)
+    #[cfg(feature = "sync")]
+    pub fn update_dispute_if_editable_blocking(
+        &self,
+        dispute: &Dispute,
+        request: &UpdateDisputeRequest,
+    ) -> Result<()> {
+        ensure_editable(dispute)?;
+        self.update_dispute_blocking(&dispute.id, request)
+    }
+
     /// Get a dispute's file evidence (blocking)
     #[cfg(feature = "sync")]
     pub fn get_dispute_evidence_blocking(&self, dispute_id: &Uuid) -> Result<Vec<u8>> {
@@ -202,6 +510,16 @@ impl RainClient {
         self.get_bytes_blocking(&path)
     }
 
+    /// Get a dispute's file evidence with its content type (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_dispute_evidence_with_type_blocking(
+        &self,
+        dispute_id: &Uuid,
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        let path = format!("/disputes/{dispute_id}/evidence");
+        self.get_bytes_with_type_blocking(&path)
+    }
+
     /// Upload a file as evidence for a dispute (blocking)
     #[cfg(feature = "sync")]
     pub fn upload_dispute_evidence_blocking(
@@ -211,18 +529,49 @@ impl RainClient {
     ) -> Result<()> {
         let path = format!("/disputes/{dispute_id}/evidence");
 
-        use reqwest::blocking::multipart::{Form, Part};
-        let form = Form::new()
-            .text("name", request.name.clone())
-            .text("type", request.evidence_type.clone())
-            .part(
-                "evidence",
-                Part::bytes(request.file.clone()).file_name(request.name.clone()),
-            );
+        let file = reqwest::blocking::multipart::Part::bytes(request.file.clone())
+            .file_name(request.name.clone());
+        let fields = [
+            ("name", request.name.clone()),
+            ("type", request.evidence_type.as_str().to_string()),
+        ];
 
-        let url = self.build_url(&path)?;
-        let response = self.put_multipart_blocking_no_content(&path, form)?;
-        Ok(response)
+        self.put_multipart_with_fields_blocking::<IgnoredAny>(&path, "evidence", file, &fields)?;
+        Ok(())
+    }
+
+    /// Upload a file as evidence for a dispute, unless it can no longer be edited (blocking)
+    #[cfg(feature = "sync")]
+    pub fn upload_dispute_evidence_if_editable_blocking(
+        &self,
+        dispute: &Dispute,
+        request: &UploadDisputeEvidenceRequest,
+    ) -> Result<()> {
+        ensure_editable(dispute)?;
+        self.upload_dispute_evidence_blocking(&dispute.id, request)
+    }
+
+    /// Upload a file from disk as evidence for a dispute (blocking)
+    #[cfg(feature = "sync")]
+    pub fn upload_dispute_evidence_from_path_blocking(
+        &self,
+        dispute_id: &Uuid,
+        path: impl AsRef<Path>,
+        evidence_type: EvidenceType,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let file = std::fs::read(path).map_err(|e| {
+            RainError::Other(anyhow::anyhow!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let request = UploadDisputeEvidenceRequest {
+            name: evidence_file_name(path),
+            evidence_type,
+            file,
+        };
+        self.upload_dispute_evidence_blocking(dispute_id, &request)
     }
 
     /// Create a dispute for a transaction (blocking)
@@ -236,3 +585,49 @@ impl RainClient {
         self.post_blocking(&path, request)
     }
 }
+
+/// Most pages [`RainClient::all_disputes`] fetches before giving up
+pub const MAX_DISPUTE_PAGES: usize = 1_000;
+
+/// Parameters for the page after `page`, or `None` if it was the last
+#[cfg(any(feature = "async", feature = "sync"))]
+fn next_disputes_page(params: ListDisputesParams, page: &[Dispute]) -> Option<ListDisputesParams> {
+    let full_page = page.len() >= page_limit(params.limit) as usize;
+    match page.last() {
+        Some(last) if full_page => Some(ListDisputesParams {
+            cursor: Some(last.id.to_string()),
+            ..params
+        }),
+        _ => None,
+    }
+}
+
+/// Error for a dispute listing longer than [`MAX_DISPUTE_PAGES`]
+#[cfg(any(feature = "async", feature = "sync"))]
+fn too_many_dispute_pages() -> RainError {
+    RainError::Other(anyhow::anyhow!(
+        "Disputes span more than {MAX_DISPUTE_PAGES} pages; narrow the filters"
+    ))
+}
+
+/// Derive the evidence name from the file name of a path
+#[cfg(any(feature = "async", feature = "sync"))]
+fn evidence_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "evidence".to_string())
+}
+
+/// Fail with a conflict if a dispute can no longer be edited
+#[cfg(any(feature = "async", feature = "sync"))]
+fn ensure_editable(dispute: &Dispute) -> Result<()> {
+    if dispute.is_editable() {
+        Ok(())
+    } else {
+        let reason = ConflictReason::DisputeNotEditable;
+        Err(RainError::Conflict {
+            response: Box::new(ApiErrorResponse::new(reason.to_string())),
+            reason,
+        })
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! This module provides functionality to manage API keys.
 
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::client::access_error;
 use crate::client::RainClient;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::config::Environment;
 use crate::error::Result;
 use crate::models::keys::*;
 use uuid::Uuid;
@@ -67,9 +71,13 @@ impl RainClient {
     ///
     /// This method can return the following errors:
     /// - `401` - Invalid authorization
-    /// - `404` - Key not found
+    /// - `403` - The key belongs to someone else, returned as [`RainError::Forbidden`]
+    /// - `404` - Key not found, returned as [`RainError::NotFound`]
     /// - `500` - Internal server error
     ///
+    /// [`RainError::Forbidden`]: crate::RainError::Forbidden
+    /// [`RainError::NotFound`]: crate::RainError::NotFound
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -90,7 +98,39 @@ impl RainClient {
     #[cfg(feature = "async")]
     pub async fn delete_key(&self, key_id: &Uuid) -> Result<()> {
         let path = format!("/keys/{key_id}");
-        self.delete(&path).await
+        self.delete(&path).await.map_err(access_error)
+    }
+
+    /// Get the identity behind the current API key
+    ///
+    /// The Rain API has no endpoint describing the tenant, name or scopes of an API
+    /// key, and none of them can be read from another call, so only
+    /// [`TenantIdentity::environment`] is filled in, from the configured base URL.
+    /// No request is sent.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`TenantIdentity`] whose other fields are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let identity = client.whoami().await?;
+    /// println!("Using {:?}", identity.environment);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn whoami(&self) -> Result<TenantIdentity> {
+        Ok(self.identity())
     }
 
     // ============================================================================
@@ -108,6 +148,28 @@ impl RainClient {
     #[cfg(feature = "sync")]
     pub fn delete_key_blocking(&self, key_id: &Uuid) -> Result<()> {
         let path = format!("/keys/{key_id}");
-        self.delete_blocking(&path)
+        self.delete_blocking(&path).map_err(access_error)
+    }
+
+    /// Get the identity behind the current API key (blocking)
+    #[cfg(feature = "sync")]
+    pub fn whoami_blocking(&self) -> Result<TenantIdentity> {
+        Ok(self.identity())
+    }
+
+    /// The identity known without asking the API
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn identity(&self) -> TenantIdentity {
+        let base_url = self.base_url();
+        let environment = [Environment::Dev, Environment::Production]
+            .into_iter()
+            .find(|environment| environment.base_url() == *base_url)
+            .unwrap_or_else(|| Environment::Custom(base_url.clone()));
+        TenantIdentity {
+            tenant_id: None,
+            name: None,
+            environment,
+            scopes: None,
+        }
     }
 }
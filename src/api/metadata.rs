@@ -0,0 +1,58 @@
+//! Metadata API
+//!
+//! Reference data such as the countries and currencies the API accepts. The Rain
+//! API has no endpoint listing them, so these methods report
+//! [`RainError::Unsupported`](crate::error::RainError::Unsupported) instead of
+//! guessing at a path; check the supported values with Rain.
+
+use crate::client::RainClient;
+use crate::error::Result;
+use crate::models::metadata::*;
+
+impl RainClient {
+    /// Get the countries supported for applications and shipping
+    ///
+    /// # Errors
+    ///
+    /// Always returns [`RainError::Unsupported`](crate::error::RainError::Unsupported)
+    /// without sending a request, as the API does not list supported countries.
+    #[cfg(feature = "async")]
+    pub async fn get_supported_countries(&self) -> Result<Vec<Country>> {
+        Err(metadata_unsupported("countries"))
+    }
+
+    /// Get the currencies supported for cards
+    ///
+    /// # Errors
+    ///
+    /// Always returns [`RainError::Unsupported`](crate::error::RainError::Unsupported)
+    /// without sending a request, as the API does not list supported currencies.
+    #[cfg(feature = "async")]
+    pub async fn get_supported_currencies(&self) -> Result<Vec<Currency>> {
+        Err(metadata_unsupported("currencies"))
+    }
+
+    // ============================================================================
+    // Blocking Methods
+    // ============================================================================
+
+    /// Get the countries supported for applications and shipping (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_supported_countries_blocking(&self) -> Result<Vec<Country>> {
+        Err(metadata_unsupported("countries"))
+    }
+
+    /// Get the currencies supported for cards (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_supported_currencies_blocking(&self) -> Result<Vec<Currency>> {
+        Err(metadata_unsupported("currencies"))
+    }
+}
+
+/// Error for listing reference data the API does not expose
+#[cfg(any(feature = "async", feature = "sync"))]
+fn metadata_unsupported(what: &str) -> crate::error::RainError {
+    crate::error::RainError::Unsupported(format!(
+        "the API has no endpoint listing supported {what}"
+    ))
+}
@@ -7,6 +7,7 @@ pub mod companies;
 pub mod contracts;
 pub mod disputes;
 pub mod keys;
+pub mod metadata;
 pub mod payments;
 pub mod reports;
 pub mod shipping_groups;
@@ -2,7 +2,7 @@
 //!
 //! This module provides functionality to get tenant reports.
 
-use crate::client::RainClient;
+use crate::client::{encode_query, RainClient};
 use crate::error::Result;
 use crate::models::reports::*;
 
@@ -55,18 +55,7 @@ impl RainClient {
         day: &str,
         params: &GetReportParams,
     ) -> Result<Vec<u8>> {
-        let base_url = self.base_url();
-        let mut url = base_url.clone();
-        url.path_segments_mut()
-            .map_err(|_| crate::error::RainError::Other(anyhow::anyhow!("Cannot be a base URL")))?
-            .pop_if_empty()
-            .push("issuing")
-            .push("reports")
-            .push(year)
-            .push(month)
-            .push(day);
-
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             format!("/reports/{year}/{month}/{day}")
         } else {
@@ -88,7 +77,7 @@ impl RainClient {
         day: &str,
         params: &GetReportParams,
     ) -> Result<Vec<u8>> {
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             format!("/reports/{year}/{month}/{day}")
         } else {
@@ -2,8 +2,9 @@
 //!
 //! This module provides functionality to manage bulk shipping groups.
 
-use crate::client::RainClient;
-use crate::error::Result;
+use crate::client::wait::WaitConfig;
+use crate::client::{encode_query, RainClient};
+use crate::error::{RainError, Result};
 use crate::models::shipping_groups::*;
 use uuid::Uuid;
 
@@ -50,7 +51,7 @@ impl RainClient {
         params: &ListShippingGroupsParams,
     ) -> Result<Vec<ShippingGroup>> {
         let path = "/shipping-groups";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -140,6 +141,62 @@ impl RainClient {
         self.get(&path).await
     }
 
+    /// Wait for a bulk shipping group to ship
+    ///
+    /// Polls [`get_shipping_group`](Self::get_shipping_group) every `wait.interval`
+    /// until the group is shipped or delivered.
+    ///
+    /// # Arguments
+    ///
+    /// * `shipping_group_id` - The unique identifier of the shipping group
+    /// * `wait` - Polling interval and timeout
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ShippingGroup`] once its status is `shipped` or `delivered`.
+    ///
+    /// # Errors
+    ///
+    /// - [`RainError::Timeout`] if the group has not shipped before `wait.timeout`,
+    ///   including when the API never reports a [`status`](ShippingGroup::status)
+    /// - [`RainError::Other`] if the shipment failed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::client::wait::WaitConfig;
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let shipping_group_id = Uuid::new_v4();
+    /// let group = client
+    ///     .wait_for_shipped(&shipping_group_id, &WaitConfig::default())
+    ///     .await?;
+    /// println!("Tracking number: {:?}", group.tracking_number);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn wait_for_shipped(
+        &self,
+        shipping_group_id: &Uuid,
+        wait: &WaitConfig,
+    ) -> Result<ShippingGroup> {
+        crate::client::wait::poll_until(
+            wait,
+            &format!("Shipping group {shipping_group_id}"),
+            || self.get_shipping_group(shipping_group_id),
+            shipped,
+        )
+        .await
+    }
+
     // ============================================================================
     // Blocking Methods
     // ============================================================================
@@ -151,7 +208,7 @@ impl RainClient {
         params: &ListShippingGroupsParams,
     ) -> Result<Vec<ShippingGroup>> {
         let path = "/shipping-groups";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -177,4 +234,31 @@ impl RainClient {
         let path = format!("/shipping-groups/{shipping_group_id}");
         self.get_blocking(&path)
     }
+
+    /// Wait for a bulk shipping group to ship (blocking)
+    #[cfg(feature = "sync")]
+    pub fn wait_for_shipped_blocking(
+        &self,
+        shipping_group_id: &Uuid,
+        wait: &WaitConfig,
+    ) -> Result<ShippingGroup> {
+        crate::client::wait::poll_until_blocking(
+            wait,
+            &format!("Shipping group {shipping_group_id}"),
+            || self.get_shipping_group_blocking(shipping_group_id),
+            shipped,
+        )
+    }
+}
+
+/// Check whether a polled shipping group is shipped, failing on a terminal error
+#[cfg(any(feature = "async", feature = "sync"))]
+fn shipped(group: &ShippingGroup) -> Result<bool> {
+    if group.status == Some(ShippingGroupStatus::Failed) {
+        return Err(RainError::Other(anyhow::anyhow!(
+            "Shipping group {} failed to ship",
+            group.id
+        )));
+    }
+    Ok(group.is_shipped())
 }
@@ -2,9 +2,13 @@
 //!
 //! This module provides functionality to get payment and withdrawal signatures.
 
-use crate::client::RainClient;
+use crate::client::{encode_query, RainClient};
 use crate::error::Result;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::error::{ConflictReason, RainError};
 use crate::models::signatures::*;
+#[cfg(any(feature = "async", feature = "sync"))]
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 impl RainClient {
@@ -44,13 +48,13 @@ impl RainClient {
     /// let company_id = Uuid::new_v4();
     /// let params = PaymentSignatureParams {
     ///     chain_id: Some(1),
-    ///     token: "0xabc123...".to_string(),
+    ///     token: "0x...".to_string(),
     ///     amount: "1000000".to_string(),
-    ///     admin_address: "0xdef456...".to_string(),
+    ///     admin_address: "0x...".to_string(),
     ///     is_amount_native: Some(false),
     ///     rain_collateral_contract_id: None,
     /// };
-    /// let response = client.get_company_payment_signature(&company_id, &params).await?;
+    /// let signature = client.get_company_payment_signature(&company_id, &params).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -61,7 +65,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = format!("/companies/{company_id}/signatures/payments");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -83,6 +87,9 @@ impl RainClient {
     ///
     /// # Errors
     ///
+    /// Returns [`RainError::ValidationError`](crate::error::RainError::ValidationError) without
+    /// sending a request if [`WithdrawalSignatureParams::validate`] fails.
+    ///
     /// This method can return the following errors:
     /// - `400` - Invalid request
     /// - `401` - Invalid authorization
@@ -104,15 +111,14 @@ impl RainClient {
     /// let client = RainClient::new(config, auth)?;
     ///
     /// let company_id = Uuid::new_v4();
-    /// let params = WithdrawalSignatureParams {
-    ///     chain_id: Some(1),
-    ///     token: "0xabc123...".to_string(),
-    ///     amount: "500000".to_string(),
-    ///     admin_address: "0xdef456...".to_string(),
-    ///     recipient_address: "0x789ghi...".to_string(),
-    ///     is_amount_native: Some(false),
-    ///     rain_collateral_contract_id: None,
-    /// };
+    /// let params = WithdrawalSignatureParams::builder()
+    ///     .chain_id(1)
+    ///     .token("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")
+    ///     .amount("500000")
+    ///     .admin_address("0x1111111111111111111111111111111111111111")
+    ///     .recipient_address("0x2222222222222222222222222222222222222222")
+    ///     .is_amount_native(false)
+    ///     .build()?;
     /// let response = client.get_company_withdrawal_signature(&company_id, &params).await?;
     /// # Ok(())
     /// # }
@@ -123,8 +129,9 @@ impl RainClient {
         company_id: &Uuid,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = format!("/companies/{company_id}/signatures/withdrawals");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -133,6 +140,80 @@ impl RainClient {
         self.get(&full_path).await
     }
 
+    /// Get payment signature for a company, waiting out the active one on a conflict
+    ///
+    /// The Rain API has no endpoint to cancel a signature: an active signature stays
+    /// in place until it expires, and requesting another before then fails with
+    /// [`ConflictReason::ActiveSignatureExists`](crate::error::ConflictReason::ActiveSignatureExists).
+    /// On that conflict, this method waits until `active_expires_at`, the
+    /// `expires_at` of the signature the caller already holds, and requests the
+    /// signature once more.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_id` - The unique identifier of the company
+    /// * `params` - Query parameters for the signature request
+    /// * `active_expires_at` - When the active signature expires, if known
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PaymentSignatureResponse`] which can be either pending or ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns the conflict unchanged if `active_expires_at` is `None`, and otherwise
+    /// the same errors as [`get_company_payment_signature`](Self::get_company_payment_signature).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::signatures::PaymentSignatureParams;
+    /// use chrono::{TimeDelta, Utc};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let company_id = Uuid::new_v4();
+    /// let params = PaymentSignatureParams {
+    ///     chain_id: Some(1),
+    ///     token: "0x...".to_string(),
+    ///     amount: "1000000".to_string(),
+    ///     admin_address: "0x...".to_string(),
+    ///     is_amount_native: None,
+    ///     rain_collateral_contract_id: None,
+    /// };
+    /// // When the signature handed out earlier expires
+    /// let active_expires_at = Utc::now() + TimeDelta::minutes(5);
+    /// let signature = client
+    ///     .get_or_replace_payment_signature(&company_id, &params, Some(active_expires_at))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_or_replace_payment_signature(
+        &self,
+        company_id: &Uuid,
+        params: &PaymentSignatureParams,
+        active_expires_at: Option<DateTime<Utc>>,
+    ) -> Result<PaymentSignatureResponse> {
+        match self.get_company_payment_signature(company_id, params).await {
+            Err(err) if active_signature_exists(&err) => {
+                let Some(wait) = active_expires_at.map(|at| until(self.now(), at)) else {
+                    return Err(err);
+                };
+                tokio::time::sleep(wait).await;
+                self.get_company_payment_signature(company_id, params).await
+            }
+            result => result,
+        }
+    }
+
     /// Get payment signature for an authorized user tenant
     ///
     /// # Arguments
@@ -156,7 +237,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = "/signatures/payments";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -177,6 +258,9 @@ impl RainClient {
     ///
     /// # Errors
     ///
+    /// Returns [`RainError::ValidationError`](crate::error::RainError::ValidationError) without
+    /// sending a request if [`WithdrawalSignatureParams::validate`] fails.
+    ///
     /// This method can return the following errors:
     /// - `400` - Invalid request
     /// - `401` - Invalid authorization
@@ -187,8 +271,9 @@ impl RainClient {
         &self,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = "/signatures/withdrawals";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -223,7 +308,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = format!("/users/{user_id}/signatures/payments");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -245,6 +330,9 @@ impl RainClient {
     ///
     /// # Errors
     ///
+    /// Returns [`RainError::ValidationError`](crate::error::RainError::ValidationError) without
+    /// sending a request if [`WithdrawalSignatureParams::validate`] fails.
+    ///
     /// This method can return the following errors:
     /// - `400` - Invalid request
     /// - `401` - Invalid authorization
@@ -257,8 +345,9 @@ impl RainClient {
         user_id: &Uuid,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = format!("/users/{user_id}/signatures/withdrawals");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -279,7 +368,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = format!("/companies/{company_id}/signatures/payments");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -295,8 +384,9 @@ impl RainClient {
         company_id: &Uuid,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = format!("/companies/{company_id}/signatures/withdrawals");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -312,7 +402,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = "/signatures/payments";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -327,8 +417,9 @@ impl RainClient {
         &self,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = "/signatures/withdrawals";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -345,7 +436,7 @@ impl RainClient {
         params: &PaymentSignatureParams,
     ) -> Result<PaymentSignatureResponse> {
         let path = format!("/users/{user_id}/signatures/payments");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -361,8 +452,9 @@ impl RainClient {
         user_id: &Uuid,
         params: &WithdrawalSignatureParams,
     ) -> Result<WithdrawalSignatureResponse> {
+        params.validate()?;
         let path = format!("/users/{user_id}/signatures/withdrawals");
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path
         } else {
@@ -370,4 +462,42 @@ impl RainClient {
         };
         self.get_blocking(&full_path)
     }
+
+    /// Get payment signature for a company, waiting out the active one on a conflict (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_or_replace_payment_signature_blocking(
+        &self,
+        company_id: &Uuid,
+        params: &PaymentSignatureParams,
+        active_expires_at: Option<DateTime<Utc>>,
+    ) -> Result<PaymentSignatureResponse> {
+        match self.get_company_payment_signature_blocking(company_id, params) {
+            Err(err) if active_signature_exists(&err) => {
+                let Some(wait) = active_expires_at.map(|at| until(self.now(), at)) else {
+                    return Err(err);
+                };
+                std::thread::sleep(wait);
+                self.get_company_payment_signature_blocking(company_id, params)
+            }
+            result => result,
+        }
+    }
+}
+
+/// Whether an error is the conflict for an already active signature
+#[cfg(any(feature = "async", feature = "sync"))]
+fn active_signature_exists(err: &RainError) -> bool {
+    matches!(
+        err,
+        RainError::Conflict {
+            reason: ConflictReason::ActiveSignatureExists,
+            ..
+        }
+    )
+}
+
+/// How long from `now` until `at`, zero if it has passed
+#[cfg(any(feature = "async", feature = "sync"))]
+fn until(now: DateTime<Utc>, at: DateTime<Utc>) -> std::time::Duration {
+    (at - now).to_std().unwrap_or_default()
 }
@@ -4,7 +4,21 @@
 
 use crate::client::RainClient;
 use crate::error::Result;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::models::common::page_limit;
 use crate::models::transactions::*;
+#[cfg(feature = "async")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "async")]
+use futures_util::stream::{self, Stream, StreamExt};
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
+#[cfg(feature = "async")]
+use std::fmt;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
 use uuid::Uuid;
 
 impl RainClient {
@@ -29,7 +43,7 @@ impl RainClient {
         params: &ListTransactionsParams,
     ) -> Result<Vec<Transaction>> {
         let path = "/transactions";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = params.to_query_string()?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -38,6 +52,191 @@ impl RainClient {
         self.get(&full_path).await
     }
 
+    /// Stream every transaction matching the filters, following pagination
+    ///
+    /// Pages are fetched lazily as the stream is polled, using the id of the last
+    /// transaction in each page as the cursor for the next. A page shorter than the
+    /// page size, `params.limit` or [`DEFAULT_PAGE_SIZE`](crate::models::common::DEFAULT_PAGE_SIZE)
+    /// if unset, ends the stream.
+    /// Leave `company_id`, `user_id` and `card_id` unset to list across all entities.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters to filter transactions; `limit` sets the page size
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of [`Transaction`] that stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    /// use rain_sdk::models::transactions::ListTransactionsParams;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let params = ListTransactionsParams {
+    ///     limit: Some(100),
+    ///     ..Default::default()
+    /// };
+    /// let transactions: Vec<_> = client.list_all_transactions(&params).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn list_all_transactions<'a>(
+        &'a self,
+        params: &ListTransactionsParams,
+    ) -> impl Stream<Item = Result<Transaction>> + 'a {
+        let state = (VecDeque::new(), Some(params.clone()));
+        stream::try_unfold(state, move |(mut buffer, mut next)| async move {
+            loop {
+                if let Some(transaction) = buffer.pop_front() {
+                    return Ok(Some((transaction, (buffer, next))));
+                }
+                let Some(params) = next.take() else {
+                    return Ok(None);
+                };
+
+                let page = self.list_transactions(&params).await?;
+                next = next_page(params, &page);
+                buffer.extend(page);
+            }
+        })
+    }
+
+    /// Stream transactions posted after a checkpoint, for incremental sync
+    ///
+    /// Lists transactions with `postedAfter` set to `checkpoint`, following
+    /// pagination like [`list_all_transactions`](Self::list_all_transactions). Pages
+    /// are not ordered by `postedAt`, so the new high-watermark, the latest
+    /// `postedAt` seen, is only known once every page has been read: it is available
+    /// from [`TransactionSync::watermark`] after the stream has ended without an
+    /// error. Persist it only after handling every transaction, and pass it as the
+    /// next checkpoint.
+    ///
+    /// Delivery is at least once: if a sync stops early, run it again from the old
+    /// checkpoint and the transactions already handled are listed again, so handle
+    /// them idempotently, for example keyed by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint` - Only transactions posted after this time are listed
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`TransactionSync`] stream of transactions, which stops after the
+    /// first error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use chrono::{TimeZone, Utc};
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let checkpoint = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let mut sync = client.sync_transactions_since(checkpoint);
+    /// while let Some(transaction) = sync.next().await {
+    ///     let transaction = transaction?;
+    /// }
+    /// // Store this and pass it to the next sync
+    /// let checkpoint = sync.watermark().unwrap_or(checkpoint);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn sync_transactions_since(&self, checkpoint: DateTime<Utc>) -> TransactionSync<'_> {
+        let params = ListTransactionsParams {
+            posted_after: Some(checkpoint),
+            ..Default::default()
+        };
+        TransactionSync {
+            transactions: Box::pin(self.list_all_transactions(&params)),
+            latest: checkpoint,
+            finished: false,
+            failed: false,
+        }
+    }
+
+    /// Export every transaction matching the filters as CSV
+    ///
+    /// Follows pagination like [`list_all_transactions`](Self::list_all_transactions)
+    /// and writes a header followed by one row per transaction, with the columns
+    /// `id`, `type`, `amount`, `amount_unit`, `currency`, `merchant`, `date` and
+    /// `status`. Columns that do not apply to a transaction type are left blank: only
+    /// spends have a merchant, fees have no currency, and only spends and payments
+    /// have a status. The date is when the transaction posted, or for a spend not yet
+    /// posted, when it was authorized.
+    ///
+    /// Amounts are written as they are held, so `amount_unit` says how to read them:
+    /// `cents` for spends, payments and fees, and `tokens` for collateral, whose
+    /// amount is a decimal number of tokens of its currency. A merchant name starting
+    /// with `=`, `+`, `-`, `@`, a tab or a carriage return is prefixed with `'` so
+    /// spreadsheets show it as text instead of evaluating it as a formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters to filter transactions; `limit` sets the page size
+    /// * `writer` - Where to write the CSV
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of transactions written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first listing error, or [`RainError::Other`](crate::error::RainError::Other)
+    /// if writing fails. Rows written before the error are left in `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::models::transactions::ListTransactionsParams;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let file = std::fs::File::create("transactions.csv")?;
+    /// let written = client
+    ///     .export_transactions_csv(&ListTransactionsParams::default(), file)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn export_transactions_csv(
+        &self,
+        params: &ListTransactionsParams,
+        mut writer: impl std::io::Write,
+    ) -> Result<usize> {
+        write_csv_row(&mut writer, &CSV_COLUMNS.map(String::from))?;
+        let mut transactions = std::pin::pin!(self.list_all_transactions(params));
+        let mut written = 0;
+        while let Some(transaction) = transactions.next().await {
+            write_csv_row(&mut writer, &csv_row(&transaction?))?;
+            written += 1;
+        }
+        writer.flush().map_err(csv_write_error)?;
+        Ok(written)
+    }
+
     /// Get a transaction by its id
     ///
     /// # Arguments
@@ -104,6 +303,45 @@ impl RainClient {
         self.get_bytes(&path).await
     }
 
+    /// Get a transaction's receipt with its content type
+    ///
+    /// Use the content type to serve the receipt back with the right headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The unique identifier of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the receipt as raw bytes, and the response's `Content-Type` if it
+    /// had one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use uuid::Uuid;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let transaction_id = Uuid::new_v4();
+    /// let (bytes, content_type) = client.get_transaction_receipt_with_type(&transaction_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_transaction_receipt_with_type(
+        &self,
+        transaction_id: &Uuid,
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        let path = format!("/transactions/{transaction_id}/receipt");
+        self.get_bytes_with_type(&path).await
+    }
+
     /// Upload a transaction's receipt
     ///
     /// # Arguments
@@ -142,7 +380,7 @@ impl RainClient {
         params: &ListTransactionsParams,
     ) -> Result<Vec<Transaction>> {
         let path = "/transactions";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = params.to_query_string()?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -151,6 +389,22 @@ impl RainClient {
         self.get_blocking(&full_path)
     }
 
+    /// Get every transaction matching the filters, following pagination (blocking)
+    #[cfg(feature = "sync")]
+    pub fn list_all_transactions_blocking(
+        &self,
+        params: &ListTransactionsParams,
+    ) -> Result<Vec<Transaction>> {
+        let mut transactions = Vec::new();
+        let mut next = Some(params.clone());
+        while let Some(params) = next {
+            let page = self.list_transactions_blocking(&params)?;
+            next = next_page(params, &page);
+            transactions.extend(page);
+        }
+        Ok(transactions)
+    }
+
     /// Get a transaction by its id (blocking)
     #[cfg(feature = "sync")]
     pub fn get_transaction_blocking(&self, transaction_id: &Uuid) -> Result<Transaction> {
@@ -177,6 +431,16 @@ impl RainClient {
         self.get_bytes_blocking(&path)
     }
 
+    /// Get a transaction's receipt with its content type (blocking)
+    #[cfg(feature = "sync")]
+    pub fn get_transaction_receipt_with_type_blocking(
+        &self,
+        transaction_id: &Uuid,
+    ) -> Result<(Vec<u8>, Option<String>)> {
+        let path = format!("/transactions/{transaction_id}/receipt");
+        self.get_bytes_with_type_blocking(&path)
+    }
+
     /// Upload a transaction's receipt (blocking)
     #[cfg(feature = "sync")]
     pub fn upload_transaction_receipt_blocking(
@@ -195,3 +459,178 @@ impl RainClient {
         self.put_multipart_blocking_no_content(&path, form)
     }
 }
+
+/// Query for the page after `page`, or `None` once a short page shows the end
+#[cfg(any(feature = "async", feature = "sync"))]
+fn next_page(
+    params: ListTransactionsParams,
+    page: &[Transaction],
+) -> Option<ListTransactionsParams> {
+    let full_page = page.len() >= page_limit(params.limit) as usize;
+    match page.last() {
+        Some(last) if full_page => Some(ListTransactionsParams {
+            cursor: Some(last.id().to_string()),
+            ..params
+        }),
+        _ => None,
+    }
+}
+
+/// Stream of transactions from [`RainClient::sync_transactions_since`]
+///
+/// Yields each transaction posted after the checkpoint, then records the
+/// high-watermark once the stream has ended.
+#[cfg(feature = "async")]
+pub struct TransactionSync<'a> {
+    transactions: Pin<Box<dyn Stream<Item = Result<Transaction>> + Send + 'a>>,
+    latest: DateTime<Utc>,
+    finished: bool,
+    failed: bool,
+}
+
+#[cfg(feature = "async")]
+impl TransactionSync<'_> {
+    /// The checkpoint for the next sync, once every transaction has been listed
+    ///
+    /// This is the latest `postedAt` among the listed transactions, or the
+    /// checkpoint the sync started from if none posted later. Returns `None` while
+    /// the stream still has transactions to yield, and after an error.
+    pub fn watermark(&self) -> Option<DateTime<Utc>> {
+        (self.finished && !self.failed).then_some(self.latest)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Stream for TransactionSync<'_> {
+    type Item = Result<Transaction>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let polled = self.transactions.as_mut().poll_next(cx);
+        match &polled {
+            Poll::Ready(Some(Ok(transaction))) => {
+                if let Some(posted_at) = transaction.posted_at() {
+                    self.latest = self.latest.max(posted_at);
+                }
+            }
+            Poll::Ready(Some(Err(_))) => self.failed = true,
+            Poll::Ready(None) => self.finished = true,
+            Poll::Pending => {}
+        }
+        polled
+    }
+}
+
+#[cfg(feature = "async")]
+impl fmt::Debug for TransactionSync<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionSync")
+            .field("latest", &self.latest)
+            .field("finished", &self.finished)
+            .field("failed", &self.failed)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Columns written by [`RainClient::export_transactions_csv`]
+#[cfg(feature = "async")]
+const CSV_COLUMNS: [&str; 8] = [
+    "id",
+    "type",
+    "amount",
+    "amount_unit",
+    "currency",
+    "merchant",
+    "date",
+    "status",
+];
+
+/// The CSV fields of a transaction, blank where a column does not apply
+#[cfg(feature = "async")]
+fn csv_row(transaction: &Transaction) -> [String; 8] {
+    let date = |at: Option<DateTime<Utc>>| at.map(|at| at.to_rfc3339()).unwrap_or_default();
+    let id = transaction.id().to_string();
+    match transaction {
+        Transaction::Spend { spend, .. } => [
+            id,
+            "spend".to_string(),
+            spend.amount.to_string(),
+            "cents".to_string(),
+            spend.currency.clone(),
+            csv_text(&spend.merchant_name),
+            date(spend.posted_at.or(Some(spend.authorized_at))),
+            wire_name(&spend.status),
+        ],
+        Transaction::Collateral { collateral, .. } => [
+            id,
+            "collateral".to_string(),
+            collateral.amount.to_string(),
+            "tokens".to_string(),
+            collateral.currency.clone(),
+            String::new(),
+            date(collateral.posted_at),
+            String::new(),
+        ],
+        Transaction::Payment { payment, .. } => [
+            id,
+            "payment".to_string(),
+            payment.amount.to_string(),
+            "cents".to_string(),
+            payment.currency.clone(),
+            String::new(),
+            date(payment.posted_at),
+            wire_name(&payment.status),
+        ],
+        Transaction::Fee { fee, .. } => [
+            id,
+            "fee".to_string(),
+            fee.amount.to_string(),
+            "cents".to_string(),
+            String::new(),
+            String::new(),
+            date(fee.posted_at),
+            String::new(),
+        ],
+    }
+}
+
+/// The name a unit enum value such as a status has on the wire
+#[cfg(feature = "async")]
+fn wire_name(value: &impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+/// Free text for a CSV field, with a leading `'` if a spreadsheet would read it as a formula
+#[cfg(feature = "async")]
+fn csv_text(text: &str) -> String {
+    if text.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Write one CSV record, quoting fields that contain a delimiter, quote or newline
+#[cfg(feature = "async")]
+fn write_csv_row(writer: &mut impl std::io::Write, fields: &[String]) -> Result<()> {
+    let line = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    writeln!(writer, "{line}").map_err(csv_write_error)
+}
+
+/// Wrap an error writing the CSV export
+#[cfg(feature = "async")]
+fn csv_write_error(err: std::io::Error) -> crate::error::RainError {
+    crate::error::RainError::Other(anyhow::anyhow!("Failed to write CSV: {err}"))
+}
@@ -5,6 +5,7 @@
 use crate::client::RainClient;
 use crate::error::Result;
 use crate::models::charges::*;
+use crate::models::common::page_limit;
 use crate::models::users::*;
 use uuid::Uuid;
 
@@ -58,9 +59,7 @@ impl RainClient {
         if let Some(ref cursor) = params.cursor {
             query_parts.push(format!("cursor={cursor}"));
         }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
+        query_parts.push(format!("limit={}", page_limit(params.limit)));
 
         if !query_parts.is_empty() {
             path.push('?');
@@ -257,6 +256,27 @@ impl RainClient {
         self.patch(&path, request).await
     }
 
+    /// Update a user only if it has not changed since `etag` was read
+    ///
+    /// Sends `If-Match: <etag>`; read the ETag with [`RainClient::get_with_etag`].
+    /// See [`RainClient::update_card_if_match`] for a full example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::PreconditionFailed`](crate::RainError::PreconditionFailed)
+    /// if the user changed since `etag` was read (HTTP 412).
+    #[cfg(feature = "async")]
+    pub async fn update_user_if_match(
+        &self,
+        user_id: &Uuid,
+        request: &UpdateUserRequest,
+        etag: &str,
+    ) -> Result<User> {
+        let path = format!("/users/{user_id}");
+        self.patch_with_headers(&path, request, vec![("If-Match", etag)])
+            .await
+    }
+
     /// Charge a user a custom fee
     ///
     /// # Arguments
@@ -293,6 +313,8 @@ impl RainClient {
     /// let request = CreateChargeRequest {
     ///     amount: 500, // $5.00 in cents
     ///     description: "Custom fee".to_string(),
+    ///     currency: None,
+    ///     metadata: None,
     /// };
     /// let charge = client.charge_user(&user_id, &request).await?;
     /// # Ok(())
@@ -324,9 +346,7 @@ impl RainClient {
         if let Some(ref cursor) = params.cursor {
             query_parts.push(format!("cursor={cursor}"));
         }
-        if let Some(limit) = params.limit {
-            query_parts.push(format!("limit={limit}"));
-        }
+        query_parts.push(format!("limit={}", page_limit(params.limit)));
 
         if !query_parts.is_empty() {
             path.push('?');
@@ -368,6 +388,18 @@ impl RainClient {
         self.patch_blocking(&path, request)
     }
 
+    /// Update a user only if it has not changed since `etag` was read (blocking)
+    #[cfg(feature = "sync")]
+    pub fn update_user_if_match_blocking(
+        &self,
+        user_id: &Uuid,
+        request: &UpdateUserRequest,
+        etag: &str,
+    ) -> Result<User> {
+        let path = format!("/users/{user_id}");
+        self.patch_with_headers_blocking(&path, request, vec![("If-Match", etag)])
+    }
+
     /// Charge a user a custom fee (blocking)
     #[cfg(feature = "sync")]
     pub fn charge_user_blocking(
@@ -2,7 +2,7 @@
 //!
 //! This module provides functionality to manage webhooks.
 
-use crate::client::RainClient;
+use crate::client::{encode_query, RainClient};
 use crate::error::Result;
 use crate::models::webhooks::*;
 use uuid::Uuid;
@@ -56,7 +56,7 @@ impl RainClient {
     #[cfg(feature = "async")]
     pub async fn list_webhooks(&self, params: &ListWebhooksParams) -> Result<Vec<Webhook>> {
         let path = "/webhooks";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
@@ -118,7 +118,7 @@ impl RainClient {
     #[cfg(feature = "sync")]
     pub fn list_webhooks_blocking(&self, params: &ListWebhooksParams) -> Result<Vec<Webhook>> {
         let path = "/webhooks";
-        let query_string = serde_urlencoded::to_string(params)?;
+        let query_string = encode_query(params)?;
         let full_path = if query_string.is_empty() {
             path.to_string()
         } else {
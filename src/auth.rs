@@ -15,6 +15,9 @@
 //! let auth = AuthConfig::with_api_key("your-api-key".to_string());
 //! ```
 
+use crate::error::{RainError, Result};
+use reqwest::header::HeaderValue;
+
 /// Authentication configuration
 ///
 /// Configures how the client authenticates with the Rain API.
@@ -53,6 +56,49 @@ impl AuthConfig {
     pub fn with_api_key(api_key: String) -> Self {
         Self { api_key }
     }
+
+    /// Check that the API key can be sent in the `Api-Key` header
+    ///
+    /// The key must be non-empty, have no surrounding whitespace and contain
+    /// only visible ASCII characters. [`RainClient::new`](crate::RainClient::new)
+    /// runs this check, so a bad key fails at construction rather than as an
+    /// opaque HTTP error on the first request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::ValidationError`] describing the problem. The key
+    /// itself is never included in the message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{AuthConfig, RainError};
+    ///
+    /// assert!(AuthConfig::with_api_key("sk_live_123".to_string()).validate().is_ok());
+    ///
+    /// for bad in ["", "sk_live_123\n", "sk_live_\n123", "clé"] {
+    ///     let result = AuthConfig::with_api_key(bad.to_string()).validate();
+    ///     assert!(matches!(result, Err(RainError::ValidationError(_))), "{bad:?}");
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        if self.api_key.is_empty() {
+            return Err(RainError::ValidationError(
+                "API key must not be empty".to_string(),
+            ));
+        }
+        if self.api_key.trim() != self.api_key {
+            return Err(RainError::ValidationError(
+                "API key must not have leading or trailing whitespace".to_string(),
+            ));
+        }
+        if !self.api_key.is_ascii() || HeaderValue::from_str(&self.api_key).is_err() {
+            return Err(RainError::ValidationError(
+                "API key must contain only visible ASCII characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Add authentication headers to a request builder
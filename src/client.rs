@@ -43,12 +43,29 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Custom Endpoints
+//!
+//! [`RainClient::get`] and [`RainClient::post`] are the supported way to
+//! call endpoints the SDK does not wrap yet. They share authentication, headers and
+//! error handling with the typed methods and deserialize into any type you define.
+
+pub mod bulk;
+pub mod rate_limit;
+pub mod retry;
+pub mod wait;
 
 use crate::auth::AuthConfig;
+use crate::client::rate_limit::RateLimitStatus;
+use crate::client::retry::RetryBudget;
 use crate::config::Config;
-use crate::error::{RainError, Result};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
-use serde::de::DeserializeOwned;
+use crate::error::{ApiErrorResponse, RainError, Result};
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CONTENT_TYPE};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "async")]
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 /// Main client for interacting with the Rain API
@@ -81,6 +98,8 @@ pub struct RainClient {
     client: reqwest::Client,
     #[cfg(feature = "sync")]
     blocking_client: reqwest::blocking::Client,
+    retry_budget: Arc<RetryBudget>,
+    rate_limit: Arc<Mutex<Option<RateLimitStatus>>>,
 }
 
 impl RainClient {
@@ -98,8 +117,16 @@ impl RainClient {
     /// # Errors
     ///
     /// This function will return an error if:
+    /// - The API key is not a valid header value (see [`AuthConfig::validate`])
     /// - The HTTP client cannot be created
     /// - The user agent string is invalid
+    /// - A header added with [`Config::with_default_header`] has an invalid name or value
+    ///
+    /// # TLS Backend
+    ///
+    /// The `rustls-tls` feature (enabled by default) uses rustls. Enabling the
+    /// `native-tls` feature switches both the async and blocking clients to the
+    /// platform's native TLS implementation.
     ///
     /// # Examples
     ///
@@ -115,46 +142,76 @@ impl RainClient {
     /// # }
     /// ```
     pub fn new(config: Config, auth_config: AuthConfig) -> Result<Self> {
+        auth_config.validate()?;
+
         #[cfg(feature = "async")]
         let client = {
-            let mut headers = HeaderMap::new();
-            headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-            headers.insert(
-                "User-Agent",
-                HeaderValue::from_str(&config.user_agent)
-                    .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
-            );
-
-            reqwest::Client::builder()
+            let headers = default_headers(&config)?;
+
+            let mut builder = reqwest::Client::builder()
                 .default_headers(headers)
                 .timeout(std::time::Duration::from_secs(config.timeout_secs))
-                .redirect(reqwest::redirect::Policy::limited(10))
-                .build()
-                .map_err(RainError::HttpError)?
+                .redirect(redirect_policy(config.max_redirects));
+
+            #[cfg(feature = "native-tls")]
+            {
+                builder = builder.use_native_tls();
+            }
+            #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+            {
+                builder = builder.use_rustls_tls();
+            }
+            if config.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            if let Some(timeout) = config.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            if let Some(timeout) = config.read_timeout {
+                builder = builder.read_timeout(timeout);
+            }
+
+            builder.build().map_err(RainError::HttpError)?
         };
 
         #[cfg(feature = "sync")]
         let blocking_client = {
-            let mut headers = HeaderMap::new();
-            headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-            headers.insert(
-                "User-Agent",
-                HeaderValue::from_str(&config.user_agent)
-                    .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
-            );
-
-            reqwest::blocking::Client::builder()
+            let headers = default_headers(&config)?;
+
+            // The blocking client has no per-read timeout, so a read timeout
+            // caps the whole request instead
+            let timeout = std::time::Duration::from_secs(config.timeout_secs);
+            let timeout = config
+                .read_timeout
+                .map_or(timeout, |read| read.min(timeout));
+
+            let mut builder = reqwest::blocking::Client::builder()
                 .default_headers(headers)
-                .timeout(std::time::Duration::from_secs(config.timeout_secs))
-                .redirect(reqwest::redirect::Policy::limited(10))
-                .build()
-                .map_err(|e| {
-                    RainError::Other(anyhow::anyhow!("Failed to create blocking client: {e}"))
-                })?
+                .timeout(timeout)
+                .redirect(redirect_policy(config.max_redirects));
+
+            #[cfg(feature = "native-tls")]
+            {
+                builder = builder.use_native_tls();
+            }
+            #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+            {
+                builder = builder.use_rustls_tls();
+            }
+            if config.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            if let Some(timeout) = config.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+
+            builder.build().map_err(|e| {
+                RainError::Other(anyhow::anyhow!("Failed to create blocking client: {e}"))
+            })?
         };
 
+        let retry_budget = RetryBudget::new(config.retry_ratio, config.retry_max_burst);
+
         Ok(Self {
             config,
             auth_config,
@@ -162,6 +219,8 @@ impl RainClient {
             client,
             #[cfg(feature = "sync")]
             blocking_client,
+            retry_budget: Arc::new(retry_budget),
+            rate_limit: Arc::default(),
         })
     }
 
@@ -189,16 +248,218 @@ impl RainClient {
         &self.config.base_url
     }
 
+    /// Get the retry budget shared by this client and its clones
+    ///
+    /// Custom retry loops should call [`RetryBudget::deposit`] for each attempt and
+    /// [`RetryBudget::try_withdraw`] before each retry, giving up with the original
+    /// error when it returns `false`.
+    pub fn retry_budget(&self) -> &RetryBudget {
+        &self.retry_budget
+    }
+
+    /// Get the rate limit reported by the most recent response
+    ///
+    /// Updated after every request made by this client or its clones. Returns
+    /// `None` until a response carries the `X-RateLimit-*` headers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let contracts = client.get_contracts().await?;
+    /// if let Some(status) = client.last_rate_limit() {
+    ///     println!("{} of {} requests left", status.remaining, status.limit);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Send a request, recording the rate limit reported by its response
+    #[cfg(feature = "async")]
+    async fn send(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let response = builder.send().await?;
+        self.record_rate_limit(response.headers());
+        Ok(response)
+    }
+
+    /// Send a blocking request, recording the rate limit reported by its response
+    #[cfg(feature = "sync")]
+    fn send_blocking(
+        &self,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response> {
+        let response = builder.send()?;
+        self.record_rate_limit(response.headers());
+        Ok(response)
+    }
+
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(status) = RateLimitStatus::from_headers(headers) {
+            *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(status);
+        }
+    }
+
+    /// Get the current time from the configured [`Clock`](crate::Clock)
+    ///
+    /// Use this rather than `Utc::now()` for clock-sensitive checks such as
+    /// [`check_webhook_timestamp`](crate::models::webhooks::check_webhook_timestamp)
+    /// so they follow [`Config::with_clock`].
+    pub fn now(&self) -> DateTime<Utc> {
+        self.config.clock.now()
+    }
+
+    /// Check that the configured API key is accepted by the API
+    ///
+    /// Makes a cheap authenticated request (`GET /balances`) so an invalid key can
+    /// be detected at startup rather than on the first real call.
+    ///
+    /// # Errors
+    ///
+    /// - [`RainError::Unauthorized`] if the API rejects the key (HTTP 401)
+    /// - Any other error if the request itself fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// client.verify_credentials().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn verify_credentials(&self) -> Result<()> {
+        self.get::<IgnoredAny>("/balances")
+            .await
+            .map(|_| ())
+            .map_err(unauthorized_error)
+    }
+
+    /// Check that the configured API key is accepted by the API (blocking)
+    #[cfg(feature = "sync")]
+    pub fn verify_credentials_blocking(&self) -> Result<()> {
+        self.get_blocking::<IgnoredAny>("/balances")
+            .map(|_| ())
+            .map_err(unauthorized_error)
+    }
+
+    /// Get the API server's current time
+    ///
+    /// The Rain API has no dedicated health or time endpoint, so this sends a
+    /// lightweight `HEAD` request to the base URL and reads the standard `Date`
+    /// response header. Compare the result with the local clock before doing
+    /// clock-sensitive work such as webhook signature verification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response has no valid `Date` header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let server_time = client.server_time().await?;
+    /// let skew = chrono::Utc::now() - server_time;
+    /// println!("Clock skew: {}s", skew.num_seconds());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn server_time(&self) -> Result<DateTime<Utc>> {
+        let response = self
+            .client
+            .head(self.config.base_url.as_str())
+            .send()
+            .await?;
+        date_header(response.headers())
+    }
+
+    /// Get the API server's current time (blocking)
+    #[cfg(feature = "sync")]
+    pub fn server_time_blocking(&self) -> Result<DateTime<Utc>> {
+        let response = self
+            .blocking_client
+            .head(self.config.base_url.as_str())
+            .send()?;
+        date_header(response.headers())
+    }
+
     /// Build a full URL from a path
     fn build_url(&self, path: &str) -> Result<Url> {
+        self.endpoint_url(path)
+    }
+
+    /// Resolve an endpoint path against the base URL
+    ///
+    /// Every request resolves its path here. Paths are relative to the base URL,
+    /// which already ends in `/v1/issuing` for Dev and Production, so they never
+    /// repeat the `/issuing` segment. A query string after `?` is kept as the URL's
+    /// query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    ///
+    /// let auth = AuthConfig::with_api_key("key".to_string());
+    /// let client = RainClient::new(Config::new(Environment::Dev), auth.clone()).unwrap();
+    ///
+    /// // The query is set as-is rather than escaped into the last segment
+    /// let url = client.endpoint_url("/users/abc/cards?status=active&cursor=a%2Fb").unwrap();
+    /// assert_eq!(url.path(), "/v1/issuing/users/abc/cards");
+    /// assert_eq!(url.query(), Some("status=active&cursor=a%2Fb"));
+    ///
+    /// // A base URL that cannot take a path is an error naming the path, not a panic
+    /// let base = url::Url::parse("mailto:ops@example.com").unwrap();
+    /// let client = RainClient::new(Config::new(Environment::Custom(base)), auth).unwrap();
+    /// let err = client.endpoint_url("/cards?limit=5").unwrap_err();
+    /// assert!(err.to_string().contains("\"/cards?limit=5\""));
+    /// ```
+    pub fn endpoint_url(&self, path: &str) -> Result<Url> {
+        // Keep any query string out of the path segments, which would escape its `?`
+        let (segments, query) = match path.split_once('?') {
+            Some((segments, query)) => (segments, Some(query)),
+            None => (path, None),
+        };
+
         // If path starts with /, we need to preserve the base URL's path
-        let path_to_join = path.strip_prefix('/').unwrap_or(path);
+        let path_to_join = segments.strip_prefix('/').unwrap_or(segments);
 
         let mut url = self.config.base_url.clone();
         url.path_segments_mut()
-            .map_err(|_| RainError::Other(anyhow::anyhow!("Cannot be a base URL")))?
+            .map_err(|_| {
+                RainError::Other(anyhow::anyhow!(
+                    "Cannot build a URL for {path:?}: base URL {} cannot be a base",
+                    self.config.base_url
+                ))
+            })?
             .pop_if_empty()
             .extend(path_to_join.split('/').filter(|s| !s.is_empty()));
+        url.set_query(query.filter(|query| !query.is_empty()));
 
         Ok(url)
     }
@@ -210,25 +471,71 @@ impl RainClient {
         let builder = self.client.get(url.as_str());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
+        let response = self.send(builder).await?;
         self.handle_response(response).await
     }
 
+    /// Make an async GET request that can be aborted with a cancellation token
+    ///
+    /// Races the request against `token`. If the token fires first the in-flight
+    /// request is dropped and [`RainError::Cancelled`] is returned. Any other
+    /// request method can be aborted the same way by racing it in `tokio::select!`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use rain_sdk::CancellationToken;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let token = CancellationToken::new();
+    /// let report: serde_json::Value = client.get_cancellable("/reports/2024/01/15", &token).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn get_cancellable<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        token: &CancellationToken,
+    ) -> Result<T> {
+        tokio::select! {
+            biased;
+            _ = token.cancelled() => Err(RainError::Cancelled),
+            result = self.get(path) => result,
+        }
+    }
+
     #[cfg(feature = "async")]
     /// Make an async GET request and return raw bytes
     pub async fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        Ok(self.get_bytes_with_type(path).await?.0)
+    }
+
+    #[cfg(feature = "async")]
+    /// Make an async GET request and return raw bytes with the `Content-Type` header
+    pub async fn get_bytes_with_type(&self, path: &str) -> Result<(Vec<u8>, Option<String>)> {
         let url = self.build_url(path)?;
         let builder = self.client.get(url.as_str());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
+        let response = self.send(builder).await?;
         let status = response.status();
         if status.is_success() {
+            let content_type = content_type(response.headers());
             let bytes = response.bytes().await?;
-            Ok(bytes.to_vec())
+            Ok((bytes.to_vec(), content_type))
         } else {
             let text = response.text().await?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
+            Err(RainError::Other(anyhow::anyhow!(
+                "HTTP {status}: {}",
+                truncate_body(&text, MAX_ERROR_BODY_LEN)
+            )))
         }
     }
 
@@ -240,11 +547,45 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
-        let builder = self.client.post(url.as_str()).body(body_bytes.clone());
+        let body_bytes = self.encode_body(body)?;
+        let builder = self
+            .client
+            .post(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
+        let response = self.send(builder).await?;
+        self.handle_response_with_body(response, &body_bytes).await
+    }
+
+    /// Make an async POST request without a body
+    ///
+    /// For endpoints that take no payload, where [`RainClient::post`] would send
+    /// `null` or `{}`. The request has an empty body and no `Content-Type` header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let _: serde::de::IgnoredAny = client.post_empty("/trigger").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.build_url(path)?;
+        let builder = self.client.post(url.as_str());
+        let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
+
+        let response = self.send(builder).await?;
         self.handle_response(response).await
     }
 
@@ -256,12 +597,41 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
-        let builder = self.client.patch(url.as_str()).body(body_bytes.clone());
+        let body_bytes = self.encode_body(body)?;
+        let builder = self
+            .client
+            .patch(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
-        self.handle_response(response).await
+        let response = self.send(builder).await?;
+        self.handle_response_with_body(response, &body_bytes).await
+    }
+
+    #[cfg(feature = "async")]
+    /// Make an async PATCH request with custom headers
+    pub async fn patch_with_headers<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Vec<(&str, &str)>,
+    ) -> Result<T> {
+        let url = self.build_url(path)?;
+        let body_bytes = self.encode_body(body)?;
+        let mut builder = self
+            .client
+            .patch(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
+        builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
+
+        for (key, value) in headers {
+            builder = builder.header(key, value);
+        }
+
+        let response = self.send(builder).await?;
+        self.handle_response_with_body(response, &body_bytes).await
     }
 
     #[cfg(feature = "async")]
@@ -272,12 +642,35 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
-        let builder = self.client.put(url.as_str()).body(body_bytes.clone());
+        let body_bytes = self.encode_body(body)?;
+        let builder = self
+            .client
+            .put(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
-        self.handle_response(response).await
+        let response = self.send(builder).await?;
+        self.handle_response_with_body(response, &body_bytes).await
+    }
+
+    #[cfg(feature = "async")]
+    /// Make an async GET request, also returning the response's `ETag` header
+    ///
+    /// Pass the ETag to a conditional update such as
+    /// [`update_card_if_match`](Self::update_card_if_match) so the update fails with
+    /// [`RainError::PreconditionFailed`] if the resource changed in between.
+    pub async fn get_with_etag<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<(T, Option<String>)> {
+        let url = self.build_url(path)?;
+        let builder = self.client.get(url.as_str());
+        let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
+
+        let response = self.send(builder).await?;
+        let etag = etag_header(response.headers());
+        Ok((self.handle_response(response).await?, etag))
     }
 
     #[cfg(feature = "async")]
@@ -295,7 +688,7 @@ impl RainClient {
             builder = builder.header(key, value);
         }
 
-        let response = builder.send().await?;
+        let response = self.send(builder).await?;
         self.handle_response(response).await
     }
 
@@ -308,16 +701,20 @@ impl RainClient {
         headers: Vec<(&str, &str)>,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
-        let mut builder = self.client.put(url.as_str()).body(body_bytes.clone());
+        let body_bytes = self.encode_body(body)?;
+        let mut builder = self
+            .client
+            .put(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
         builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
         for (key, value) in headers {
             builder = builder.header(key, value);
         }
 
-        let response = builder.send().await?;
-        self.handle_response(response).await
+        let response = self.send(builder).await?;
+        self.handle_response_with_body(response, &body_bytes).await
     }
 
     #[cfg(feature = "async")]
@@ -327,13 +724,13 @@ impl RainClient {
         let builder = self.client.delete(url.as_str());
         let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = builder.send().await?;
+        let response = self.send(builder).await?;
         let status = response.status();
         if status.is_success() || status == reqwest::StatusCode::NO_CONTENT {
             Ok(())
         } else {
             let text = response.text().await?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
+            Err(status_error(status, &text, self.config.codec.as_deref()))
         }
     }
 
@@ -345,23 +742,11 @@ impl RainClient {
         form: reqwest::multipart::Form,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let mut headers = HeaderMap::new();
-        // Don't set Content-Type for multipart - reqwest will set it with boundary
-        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-        headers.insert(
-            "User-Agent",
-            HeaderValue::from_str(&self.config.user_agent)
-                .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
-        );
-
-        let request = self
-            .client
-            .put(url.as_str())
-            .headers(headers)
-            .header("Api-Key", &self.auth_config.api_key)
-            .multipart(form);
+        // reqwest sets the multipart Content-Type (with boundary)
+        let builder = self.client.put(url.as_str()).multipart(form);
+        let builder = crate::auth::add_auth_headers_async(builder, &self.auth_config);
 
-        let response = request.send().await?;
+        let response = self.send(builder).await?;
         self.handle_response(response).await
     }
 
@@ -372,31 +757,51 @@ impl RainClient {
         path: &str,
         form: reqwest::multipart::Form,
     ) -> Result<()> {
-        let url = self.build_url(path)?;
-        let mut headers = HeaderMap::new();
-        // Don't set Content-Type for multipart - reqwest will set it with boundary
-        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-        headers.insert(
-            "User-Agent",
-            HeaderValue::from_str(&self.config.user_agent)
-                .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
-        );
-
-        let request = self
-            .client
-            .put(url.as_str())
-            .headers(headers)
-            .header("Api-Key", &self.auth_config.api_key)
-            .multipart(form);
+        self.put_multipart::<IgnoredAny>(path, form).await?;
+        Ok(())
+    }
 
-        let response = request.send().await?;
-        let status = response.status();
-        if status == reqwest::StatusCode::NO_CONTENT || status.is_success() {
-            Ok(())
-        } else {
-            let text = response.text().await?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
-        }
+    /// Make an async multipart PUT request with one file part and text fields
+    ///
+    /// Text fields are added in order, followed by the file part under
+    /// `file_field`. Use this to add new upload endpoints without building the
+    /// form by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+    /// use reqwest::multipart::Part;
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new(Environment::Dev);
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// let client = RainClient::new(config, auth)?;
+    ///
+    /// let file = Part::bytes(b"file contents".to_vec()).file_name("receipt.txt");
+    /// let fields = [("type", "receipt".to_string())];
+    /// let _: serde::de::IgnoredAny = client
+    ///     .put_multipart_with_fields("/uploads", "file", file, &fields)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn put_multipart_with_fields<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        file_field: &str,
+        file_part: reqwest::multipart::Part,
+        fields: &[(&str, String)],
+    ) -> Result<T> {
+        let form = fields
+            .iter()
+            .fold(reqwest::multipart::Form::new(), |form, (name, value)| {
+                form.text(name.to_string(), value.clone())
+            })
+            .part(file_field.to_string(), file_part);
+        self.put_multipart(path, form).await
     }
 
     #[cfg(feature = "sync")]
@@ -406,25 +811,69 @@ impl RainClient {
         let builder = self.blocking_client.get(url.as_str());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
+        let response = self.send_blocking(builder)?;
+        self.handle_blocking_response(response)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking GET request, also returning the response's `ETag` header
+    pub fn get_with_etag_blocking<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<(T, Option<String>)> {
+        let url = self.build_url(path)?;
+        let builder = self.blocking_client.get(url.as_str());
+        let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
+
+        let response = self.send_blocking(builder)?;
+        let etag = etag_header(response.headers());
+        Ok((self.handle_blocking_response(response)?, etag))
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking GET request with custom headers
+    pub fn get_with_headers_blocking<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        headers: Vec<(&str, &str)>,
+    ) -> Result<T> {
+        let url = self.build_url(path)?;
+        let mut builder = self.blocking_client.get(url.as_str());
+        builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
+
+        for (key, value) in headers {
+            builder = builder.header(key, value);
+        }
+
+        let response = self.send_blocking(builder)?;
         self.handle_blocking_response(response)
     }
 
     #[cfg(feature = "sync")]
     /// Make a blocking GET request and return raw bytes
     pub fn get_bytes_blocking(&self, path: &str) -> Result<Vec<u8>> {
+        Ok(self.get_bytes_with_type_blocking(path)?.0)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking GET request and return raw bytes with the `Content-Type` header
+    pub fn get_bytes_with_type_blocking(&self, path: &str) -> Result<(Vec<u8>, Option<String>)> {
         let url = self.build_url(path)?;
         let builder = self.blocking_client.get(url.as_str());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
+        let response = self.send_blocking(builder)?;
         let status = response.status();
         if status.is_success() {
+            let content_type = content_type(response.headers());
             let bytes = response.bytes()?;
-            Ok(bytes.to_vec())
+            Ok((bytes.to_vec(), content_type))
         } else {
             let text = response.text()?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
+            Err(RainError::Other(anyhow::anyhow!(
+                "HTTP {status}: {}",
+                truncate_body(&text, MAX_ERROR_BODY_LEN)
+            )))
         }
     }
 
@@ -436,14 +885,26 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
+        let body_bytes = self.encode_body(body)?;
         let builder = self
             .blocking_client
             .post(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
             .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
+        let response = self.send_blocking(builder)?;
+        self.handle_blocking_response_with_body(response, &body_bytes)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking POST request without a body
+    pub fn post_empty_blocking<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.build_url(path)?;
+        let builder = self.blocking_client.post(url.as_str());
+        let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
+
+        let response = self.send_blocking(builder)?;
         self.handle_blocking_response(response)
     }
 
@@ -455,15 +916,41 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
+        let body_bytes = self.encode_body(body)?;
         let builder = self
             .blocking_client
             .patch(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
             .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
-        self.handle_blocking_response(response)
+        let response = self.send_blocking(builder)?;
+        self.handle_blocking_response_with_body(response, &body_bytes)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking PATCH request with custom headers
+    pub fn patch_with_headers_blocking<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Vec<(&str, &str)>,
+    ) -> Result<T> {
+        let url = self.build_url(path)?;
+        let body_bytes = self.encode_body(body)?;
+        let mut builder = self
+            .blocking_client
+            .patch(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
+            .body(body_bytes.clone());
+        builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
+
+        for (key, value) in headers {
+            builder = builder.header(key, value);
+        }
+
+        let response = self.send_blocking(builder)?;
+        self.handle_blocking_response_with_body(response, &body_bytes)
     }
 
     #[cfg(feature = "sync")]
@@ -474,14 +961,31 @@ impl RainClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let body_bytes = serde_json::to_vec(body)?;
+        let body_bytes = self.encode_body(body)?;
         let builder = self
             .blocking_client
             .put(url.as_str())
+            .header(CONTENT_TYPE, JSON_CONTENT_TYPE)
             .body(body_bytes.clone());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
+        let response = self.send_blocking(builder)?;
+        self.handle_blocking_response_with_body(response, &body_bytes)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Make a blocking PUT request with multipart form data
+    pub fn put_multipart_blocking<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        form: reqwest::blocking::multipart::Form,
+    ) -> Result<T> {
+        let url = self.build_url(path)?;
+        // reqwest sets the multipart Content-Type (with boundary)
+        let builder = self.blocking_client.put(url.as_str()).multipart(form);
+        let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
+
+        let response = self.send_blocking(builder)?;
         self.handle_blocking_response(response)
     }
 
@@ -492,31 +996,27 @@ impl RainClient {
         path: &str,
         form: reqwest::blocking::multipart::Form,
     ) -> Result<()> {
-        let url = self.build_url(path)?;
-        use reqwest::blocking::header::{HeaderMap, HeaderValue, ACCEPT};
-        let mut headers = HeaderMap::new();
-        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-        headers.insert(
-            "User-Agent",
-            HeaderValue::from_str(&self.config.user_agent)
-                .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
-        );
-
-        let response = self
-            .blocking_client
-            .put(url.as_str())
-            .headers(headers)
-            .header("Api-Key", &self.auth_config.api_key)
-            .multipart(form)
-            .send()?;
+        self.put_multipart_blocking::<IgnoredAny>(path, form)?;
+        Ok(())
+    }
 
-        let status = response.status();
-        if status == reqwest::StatusCode::NO_CONTENT || status.is_success() {
-            Ok(())
-        } else {
-            let text = response.text()?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
-        }
+    #[cfg(feature = "sync")]
+    /// Make a blocking multipart PUT request with one file part and text fields
+    pub fn put_multipart_with_fields_blocking<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        file_field: &str,
+        file_part: reqwest::blocking::multipart::Part,
+        fields: &[(&str, String)],
+    ) -> Result<T> {
+        let form = fields
+            .iter()
+            .fold(
+                reqwest::blocking::multipart::Form::new(),
+                |form, (name, value)| form.text(name.to_string(), value.clone()),
+            )
+            .part(file_field.to_string(), file_part);
+        self.put_multipart_blocking(path, form)
     }
 
     #[cfg(feature = "sync")]
@@ -526,58 +1026,70 @@ impl RainClient {
         let builder = self.blocking_client.delete(url.as_str());
         let builder = crate::auth::add_auth_headers_sync(builder, &self.auth_config);
 
-        let response = builder.send()?;
+        let response = self.send_blocking(builder)?;
         let status = response.status();
         if status.is_success() || status == reqwest::StatusCode::NO_CONTENT {
             Ok(())
         } else {
             let text = response.text()?;
-            Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}")))
+            Err(status_error(status, &text, self.config.codec.as_deref()))
+        }
+    }
+
+    /// Serialize a request body, through the configured codec if there is one
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn encode_body<B: serde::Serialize>(&self, body: &B) -> Result<Vec<u8>> {
+        match &self.config.codec {
+            Some(codec) => codec.encode(serde_json::to_value(body)?),
+            None => Ok(serde_json::to_vec(body)?),
         }
     }
 
+    /// Handle a response to a request that carried a JSON body
+    ///
+    /// With logging enabled, 4xx errors get the redacted request body attached so
+    /// the payload the server rejected can be inspected alongside its error.
+    #[cfg(feature = "async")]
+    async fn handle_response_with_body<T: DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+        body: &[u8],
+    ) -> Result<T> {
+        let client_error = response.status().is_client_error();
+        let result = self.handle_response(response).await;
+        if client_error && self.config.enable_logging {
+            result.map_err(|e| attach_request_body(e, body))
+        } else {
+            result
+        }
+    }
+
+    /// Deserialize a response, or turn it into an error for a non-success status
+    ///
+    /// The body is parsed straight from its bytes rather than through an
+    /// intermediate `String`, so a large list page is held in memory only once
+    /// alongside the parsed value.
     #[cfg(feature = "async")]
     async fn handle_response<T: DeserializeOwned>(&self, response: reqwest::Response) -> Result<T> {
         let status = response.status();
         let url = response.url().clone();
-        let text = response.text().await?;
-
-        // Handle 202 Accepted (typically has no body)
-        if status == reqwest::StatusCode::ACCEPTED {
-            if text.is_empty() {
-                // Try to deserialize as empty JSON object for 202
-                serde_json::from_str("{}")
-                    .or_else(|_| serde_json::from_str("null"))
-                    .map_err(|_| RainError::ValidationError("Empty response body".to_string()))
-            } else {
-                serde_json::from_str(&text).map_err(RainError::DeserializationError)
-            }
-        } else if status.is_success() {
-            if text.is_empty() {
-                // Handle 204 No Content
-                serde_json::from_str("null")
-                    .map_err(|_| RainError::ValidationError("Empty response body".to_string()))
-            } else {
-                serde_json::from_str(&text).map_err(RainError::DeserializationError)
-            }
+        let body = response.bytes().await?;
+        parse_response_body(status, &url, &body, self.config.codec.as_deref())
+    }
+
+    /// Handle a blocking response to a request that carried a JSON body
+    #[cfg(feature = "sync")]
+    fn handle_blocking_response_with_body<T: DeserializeOwned>(
+        &self,
+        response: reqwest::blocking::Response,
+        body: &[u8],
+    ) -> Result<T> {
+        let client_error = response.status().is_client_error();
+        let result = self.handle_blocking_response(response);
+        if client_error && self.config.enable_logging {
+            result.map_err(|e| attach_request_body(e, body))
         } else {
-            // Try to parse as error response
-            match serde_json::from_str::<crate::error::ApiErrorResponse>(&text) {
-                Ok(api_error) => Err(RainError::ApiError {
-                    status: status.as_u16(),
-                    response: Box::new(api_error),
-                }),
-                Err(_) => Err(RainError::Other(anyhow::anyhow!(
-                    "HTTP {} from {}: {}",
-                    status,
-                    url,
-                    if text.len() > 200 {
-                        format!("{}...", &text[..200])
-                    } else {
-                        text
-                    }
-                ))),
-            }
+            result
         }
     }
 
@@ -587,35 +1099,387 @@ impl RainClient {
         response: reqwest::blocking::Response,
     ) -> Result<T> {
         let status = response.status();
-        let text = response.text()?;
-
-        // Handle 202 Accepted (typically has no body)
-        if status == reqwest::StatusCode::ACCEPTED {
-            if text.is_empty() {
-                // Try to deserialize as empty JSON object for 202
-                serde_json::from_str("{}")
-                    .or_else(|_| serde_json::from_str("null"))
-                    .map_err(|_| RainError::ValidationError("Empty response body".to_string()))
-            } else {
-                serde_json::from_str(&text).map_err(RainError::DeserializationError)
-            }
-        } else if status.is_success() {
-            if text.is_empty() {
-                // Handle 204 No Content
-                serde_json::from_str("null")
-                    .map_err(|_| RainError::ValidationError("Empty response body".to_string()))
+        let url = response.url().clone();
+        let body = response.bytes()?;
+        parse_response_body(status, &url, &body, self.config.codec.as_deref())
+    }
+}
+
+/// Deserialize a response body, or build the error for a non-success status
+///
+/// Empty `202 Accepted` and `204 No Content` bodies deserialize as `{}` or `null`.
+/// A non-empty body, success or error, goes through `codec` when one is configured;
+/// an error body the codec cannot decode is read as plain JSON.
+#[cfg(any(feature = "async", feature = "sync"))]
+fn parse_response_body<T: DeserializeOwned>(
+    status: reqwest::StatusCode,
+    url: &Url,
+    body: &[u8],
+    codec: Option<&dyn crate::codec::BodyCodec>,
+) -> Result<T> {
+    if status.is_success() {
+        if !body.is_empty() {
+            return match codec {
+                Some(codec) => serde_json::from_value(codec.decode(body)?)
+                    .map_err(RainError::DeserializationError),
+                None => serde_json::from_slice(body).map_err(RainError::DeserializationError),
+            };
+        }
+        // 202 Accepted and 204 No Content typically have no body
+        let empty = if status == reqwest::StatusCode::ACCEPTED {
+            serde_json::from_str("{}").or_else(|_| serde_json::from_str("null"))
+        } else {
+            serde_json::from_str("null")
+        };
+        return empty.map_err(|_| RainError::ValidationError("Empty response body".to_string()));
+    }
+
+    // Try to parse as error response
+    match decode_error_body(body, codec) {
+        Some(api_error) => Err(RainError::from_api_response(status.as_u16(), api_error)),
+        None => {
+            let text = String::from_utf8_lossy(body);
+            if status == reqwest::StatusCode::PRECONDITION_FAILED {
+                Err(RainError::PreconditionFailed {
+                    response: Box::new(ApiErrorResponse::new(truncate_body(
+                        &text,
+                        MAX_ERROR_BODY_LEN,
+                    ))),
+                })
             } else {
-                serde_json::from_str(&text).map_err(RainError::DeserializationError)
+                Err(RainError::Other(anyhow::anyhow!(
+                    "HTTP {status} from {url}: {}",
+                    truncate_body(&text, MAX_ERROR_BODY_LEN)
+                )))
             }
+        }
+    }
+}
+
+/// Read a response's `Content-Type` header, if present and valid text
+#[cfg(any(feature = "async", feature = "sync"))]
+fn content_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Turn a 401 API error into [`RainError::Unauthorized`]
+#[cfg(any(feature = "async", feature = "sync"))]
+fn unauthorized_error(err: RainError) -> RainError {
+    match err {
+        RainError::ApiError {
+            status: 401,
+            response,
+        } => RainError::Unauthorized(response.to_string()),
+        other => other,
+    }
+}
+
+/// Turn a 403 or 404 API error into [`RainError::Forbidden`] or [`RainError::NotFound`]
+#[cfg(any(feature = "async", feature = "sync"))]
+pub(crate) fn access_error(err: RainError) -> RainError {
+    match err {
+        RainError::ApiError {
+            status: 403,
+            response,
+        } => RainError::Forbidden(response.to_string()),
+        RainError::ApiError {
+            status: 404,
+            response,
+        } => RainError::NotFound(response.to_string()),
+        other => other,
+    }
+}
+
+/// Build the error for a non-success response whose body may not be JSON
+///
+/// A body that is not an API error object becomes the error message, so the
+/// status code is kept either way.
+#[cfg(any(feature = "async", feature = "sync"))]
+fn status_error(
+    status: reqwest::StatusCode,
+    text: &str,
+    codec: Option<&dyn crate::codec::BodyCodec>,
+) -> RainError {
+    let response = decode_error_body(text.as_bytes(), codec).unwrap_or_else(|| {
+        let message = if text.is_empty() {
+            status.to_string()
         } else {
-            // Try to parse as error response
-            match serde_json::from_str::<crate::error::ApiErrorResponse>(&text) {
-                Ok(api_error) => Err(RainError::ApiError {
-                    status: status.as_u16(),
-                    response: Box::new(api_error),
-                }),
-                Err(_) => Err(RainError::Other(anyhow::anyhow!("HTTP {status}: {text}"))),
+            truncate_body(text, MAX_ERROR_BODY_LEN)
+        };
+        crate::error::ApiErrorResponse::new(message)
+    });
+    RainError::from_api_response(status.as_u16(), response)
+}
+
+/// Read an error body through `codec` if there is one, falling back to plain JSON
+#[cfg(any(feature = "async", feature = "sync"))]
+fn decode_error_body(
+    body: &[u8],
+    codec: Option<&dyn crate::codec::BodyCodec>,
+) -> Option<crate::error::ApiErrorResponse> {
+    codec
+        .and_then(|codec| codec.decode(body).ok())
+        .and_then(|value| serde_json::from_value(value).ok())
+        .or_else(|| serde_json::from_slice(body).ok())
+}
+
+/// JSON keys whose values are replaced before a request body is shown in an error
+///
+/// Keys match whole, ignoring case, `_` and `-`, so `api_key` matches `apiKey`
+/// but a token contract address under `token` is left alone. Covers credentials
+/// and the personal details sent in applications and user updates.
+#[cfg(any(feature = "async", feature = "sync"))]
+const REDACTED_KEYS: &[&str] = &[
+    "apikey",
+    "authorization",
+    "password",
+    "secret",
+    "clientsecret",
+    "accesstoken",
+    "refreshtoken",
+    "sessionid",
+    "pin",
+    "nationalid",
+    "birthdate",
+    "email",
+    "phone",
+    "phonenumber",
+    "phonecountrycode",
+    "address",
+    "line1",
+    "line2",
+    "postalcode",
+    "ipaddress",
+];
+
+/// Whether a JSON key names a value that must not appear in an error
+#[cfg(any(feature = "async", feature = "sync"))]
+fn is_redacted_key(key: &str) -> bool {
+    let key: String = key
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect();
+    REDACTED_KEYS.contains(&key.as_str())
+}
+
+/// Attach the pretty-printed, redacted request body to an API error
+#[cfg(any(feature = "async", feature = "sync"))]
+fn attach_request_body(err: RainError, body: &[u8]) -> RainError {
+    let pretty = redacted_request_body(body);
+    match err {
+        RainError::ApiError {
+            status,
+            mut response,
+        } => {
+            response.request_body = Some(pretty);
+            RainError::ApiError { status, response }
+        }
+        RainError::Other(e) => RainError::Other(anyhow::anyhow!("{e}\nRequest body:\n{pretty}")),
+        other => other,
+    }
+}
+
+/// Pretty-print a JSON request body with credentials and personal details redacted
+#[cfg(any(feature = "async", feature = "sync"))]
+fn redacted_request_body(body: &[u8]) -> String {
+    fn redact(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if is_redacted_key(key) {
+                        *value = serde_json::Value::String("[REDACTED]".to_string());
+                    } else {
+                        redact(value);
+                    }
+                }
             }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            serde_json::to_string_pretty(&value).unwrap_or_default()
         }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Follow up to `max` redirects, refusing any that leave the original host
+#[cfg(any(feature = "async", feature = "sync"))]
+fn redirect_policy(max: usize) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        let origin = |url: &Url| {
+            (
+                url.host_str().map(str::to_owned),
+                url.port_or_known_default(),
+            )
+        };
+        let same_host = attempt
+            .previous()
+            .first()
+            .is_none_or(|first| origin(first) == origin(attempt.url()));
+        if !same_host {
+            let message = format!("refusing redirect to another host: {}", attempt.url());
+            attempt.error(message)
+        } else if attempt.previous().len() > max {
+            attempt.error(format!("too many redirects (limit {max})"))
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// Content type of JSON request bodies and of the responses the client accepts
+///
+/// Set on each request that carries a body rather than as a default header, so
+/// bodiless requests such as [`RainClient::post_empty`] send no `Content-Type`.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Build the headers sent with every request from the client configuration
+fn default_headers(config: &Config) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static(JSON_CONTENT_TYPE));
+    headers.insert(
+        "User-Agent",
+        HeaderValue::from_str(&config.user_agent)
+            .map_err(|e| RainError::Other(anyhow::anyhow!("Invalid user agent: {e}")))?,
+    );
+
+    if let Some(ref locale) = config.locale {
+        headers.insert(
+            ACCEPT_LANGUAGE,
+            HeaderValue::from_str(locale).map_err(|e| {
+                RainError::ValidationError(format!("Invalid locale {locale:?}: {e}"))
+            })?,
+        );
+    }
+
+    if let Some(ref version) = config.api_version {
+        headers.insert(
+            "Rain-Version",
+            HeaderValue::from_str(version).map_err(|e| {
+                RainError::ValidationError(format!("Invalid API version {version:?}: {e}"))
+            })?,
+        );
+    }
+
+    for (name, value) in &config.default_headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+            RainError::ValidationError(format!("Invalid default header name {name:?}: {e}"))
+        })?;
+        let header_value = HeaderValue::from_str(value).map_err(|e| {
+            RainError::ValidationError(format!("Invalid value for default header {name:?}: {e}"))
+        })?;
+        headers.insert(header_name, header_value);
+    }
+
+    Ok(headers)
+}
+
+/// Maximum number of bytes of a response body included in an error message
+#[cfg(any(feature = "async", feature = "sync"))]
+const MAX_ERROR_BODY_LEN: usize = 200;
+
+/// Shorten a body for an error message without splitting a UTF-8 character
+///
+/// Bodies longer than `max` bytes are cut at the last character boundary at or
+/// before `max` and suffixed with `…`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::truncate_body;
+///
+/// assert_eq!(truncate_body("short", 200), "short");
+///
+/// // "é" is two bytes and straddles the 200-byte limit
+/// let body = format!("{}é tail", "a".repeat(199));
+/// let truncated = truncate_body(&body, 200);
+/// assert_eq!(truncated, format!("{}…", "a".repeat(199)));
+/// ```
+pub fn truncate_body(body: &str, max: usize) -> String {
+    if body.len() <= max {
+        return body.to_string();
     }
+    let mut end = max;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &body[..end])
+}
+
+/// Encode query parameters, naming the parameters type if encoding fails
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::encode_query;
+/// use rain_sdk::RainError;
+/// use std::collections::HashMap;
+///
+/// #[derive(serde::Serialize)]
+/// struct Filters {
+///     nested: HashMap<String, u32>,
+/// }
+///
+/// let params = Filters {
+///     nested: HashMap::from([("limit".to_string(), 10)]),
+/// };
+/// let err = encode_query(&params).unwrap_err();
+/// assert!(matches!(
+///     err,
+///     RainError::QuerySerialization { params_type: "Filters", .. }
+/// ));
+/// ```
+pub fn encode_query<T: serde::Serialize>(params: &T) -> Result<String> {
+    serde_urlencoded::to_string(params).map_err(|source| {
+        let full_name = std::any::type_name::<T>();
+        let without_generics = full_name.split('<').next().unwrap_or(full_name);
+        RainError::QuerySerialization {
+            params_type: without_generics.rsplit("::").next().unwrap_or(full_name),
+            source,
+        }
+    })
+}
+
+/// Parse an HTTP date (as sent in the `Date` header) into a UTC timestamp
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use rain_sdk::client::parse_http_date;
+///
+/// let parsed = parse_http_date("Tue, 15 Nov 1994 08:12:31 GMT");
+/// assert_eq!(parsed, Some(Utc.with_ymd_and_hms(1994, 11, 15, 8, 12, 31).unwrap()));
+/// assert_eq!(parse_http_date("not a date"), None);
+/// ```
+pub fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value.trim())
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+fn date_header(headers: &HeaderMap) -> Result<DateTime<Utc>> {
+    headers
+        .get(reqwest::header::DATE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_http_date)
+        .ok_or_else(|| RainError::Other(anyhow::anyhow!("Response has no valid Date header")))
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+fn etag_header(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(reqwest::header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
 }
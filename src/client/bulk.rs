@@ -0,0 +1,100 @@
+//! Concurrency for bulk helpers
+//!
+//! Bulk helpers such as [`RainClient::get_cards`](crate::RainClient::get_cards)
+//! send one request per item with bounded concurrency. A [`BulkMode`] picks
+//! whether a failed item leaves the others running or aborts the batch.
+
+#[cfg(feature = "async")]
+use crate::error::{RainError, Result};
+#[cfg(feature = "async")]
+use futures_util::stream::{self, StreamExt};
+#[cfg(feature = "async")]
+use std::future::Future;
+
+/// How a bulk helper handles a failed item
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::bulk::BulkMode;
+///
+/// assert_eq!(BulkMode::default(), BulkMode::CollectAll);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BulkMode {
+    /// Fetch every item, returning each failure in its slot
+    #[default]
+    CollectAll,
+    /// Stop at the first failure; items abandoned because of it are
+    /// [`RainError::Cancelled`](crate::RainError::Cancelled)
+    FailFast,
+}
+
+/// Run every request with at most `concurrency` in flight, as `mode` says
+///
+/// Returns one result per request, in the order of `requests`.
+#[cfg(feature = "async")]
+pub(crate) async fn run<T, F>(
+    requests: impl IntoIterator<Item = F>,
+    concurrency: usize,
+    mode: BulkMode,
+) -> Vec<Result<T>>
+where
+    F: Future<Output = Result<T>>,
+{
+    match mode {
+        BulkMode::CollectAll => collect_all(requests, concurrency).await,
+        BulkMode::FailFast => fail_fast(requests, concurrency).await,
+    }
+}
+
+/// Run every request with at most `concurrency` in flight
+///
+/// Returns one result per request, in the order of `requests`.
+#[cfg(feature = "async")]
+async fn collect_all<T, F>(
+    requests: impl IntoIterator<Item = F>,
+    concurrency: usize,
+) -> Vec<Result<T>>
+where
+    F: Future<Output = Result<T>>,
+{
+    let mut results: Vec<(usize, Result<T>)> = stream::iter(requests.into_iter().enumerate())
+        .map(|(index, request)| async move { (index, request.await) })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Run requests with at most `concurrency` in flight until one fails
+///
+/// Returns one result per request, in the order of `requests`. Requests still in
+/// flight or not yet started when a request fails are dropped, which cancels
+/// them, and their slots hold [`RainError::Cancelled`].
+#[cfg(feature = "async")]
+async fn fail_fast<T, F>(
+    requests: impl IntoIterator<Item = F>,
+    concurrency: usize,
+) -> Vec<Result<T>>
+where
+    F: Future<Output = Result<T>>,
+{
+    let requests: Vec<F> = requests.into_iter().collect();
+    let mut results: Vec<Option<Result<T>>> = requests.iter().map(|_| None).collect();
+    let mut finished = stream::iter(requests.into_iter().enumerate())
+        .map(|(index, request)| async move { (index, request.await) })
+        .buffer_unordered(concurrency.max(1));
+    while let Some((index, result)) = finished.next().await {
+        let failed = result.is_err();
+        results[index] = Some(result);
+        if failed {
+            break;
+        }
+    }
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or(Err(RainError::Cancelled)))
+        .collect()
+}
@@ -0,0 +1,59 @@
+//! Rate limit state reported by the API
+//!
+//! Responses carry `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+//! `X-RateLimit-Reset` headers. The client records the latest values after every
+//! request so callers can slow down before they are throttled, see
+//! [`RainClient::last_rate_limit`](crate::RainClient::last_rate_limit).
+
+use reqwest::header::HeaderMap;
+
+/// Rate limit headers from the most recent response
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::rate_limit::RateLimitStatus;
+/// use reqwest::header::{HeaderMap, HeaderValue};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-ratelimit-limit", HeaderValue::from_static("100"));
+/// headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+/// headers.insert("x-ratelimit-reset", HeaderValue::from_static("30"));
+///
+/// let status = RateLimitStatus::from_headers(&headers).unwrap();
+/// assert_eq!(status.limit, 100);
+/// assert_eq!(status.remaining, 42);
+/// assert_eq!(status.reset, Some(30));
+///
+/// // Without the limit and remaining count there is nothing to report
+/// assert!(RateLimitStatus::from_headers(&HeaderMap::new()).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Requests allowed in the current window
+    pub limit: u64,
+    /// Requests left in the current window
+    pub remaining: u64,
+    /// Value of `X-RateLimit-Reset`, when the window resets, if sent
+    pub reset: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Parse the rate limit headers of a response
+    ///
+    /// Returns `None` unless both the limit and the remaining count are present
+    /// and numeric.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        Some(Self {
+            limit: number("x-ratelimit-limit")?,
+            remaining: number("x-ratelimit-remaining")?,
+            reset: number("x-ratelimit-reset"),
+        })
+    }
+}
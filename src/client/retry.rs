@@ -0,0 +1,93 @@
+//! Retry budget shared by a client's requests
+//!
+//! Retrying failed requests helps with transient errors, but when the API is
+//! struggling, every caller retrying at once multiplies its load. A
+//! [`RetryBudget`] caps retries to a fraction of the traffic that may be retried:
+//! each attempt deposits `ratio` tokens, each retry withdraws one, and a retry is
+//! dropped (the original error is returned) when no whole token is left.
+//!
+//! Every [`RainClient`](crate::RainClient) owns one budget, configured with
+//! [`Config::with_retry_budget`](crate::Config::with_retry_budget). The SDK does
+//! not retry requests in general; its few retries, such as re-reading the secrets
+//! of a new card, deposit for each attempt and withdraw before each retry. Custom
+//! retry loops can share the budget through
+//! [`RainClient::retry_budget`](crate::RainClient::retry_budget).
+
+use std::sync::Mutex;
+
+/// Token bucket limiting retries to a fraction of requests
+///
+/// # Examples
+///
+/// A burst of failures is retried only up to the budget:
+///
+/// ```rust
+/// use rain_sdk::client::retry::RetryBudget;
+///
+/// let budget = RetryBudget::new(0.25, 4);
+/// let mut retries = 0;
+/// for _ in 0..100 {
+///     budget.deposit(); // the request is sent and fails
+///     if budget.try_withdraw() {
+///         retries += 1;
+///     }
+/// }
+/// // At most the initial burst plus a quarter of the traffic
+/// assert!(retries <= 4 + 100 / 4);
+/// assert!(retries >= 100 / 4);
+///
+/// // Once exhausted, retries are refused until more requests succeed
+/// let budget = RetryBudget::new(0.5, 2);
+/// assert!(budget.try_withdraw());
+/// assert!(budget.try_withdraw());
+/// assert!(!budget.try_withdraw());
+/// budget.deposit();
+/// budget.deposit();
+/// assert!(budget.try_withdraw());
+/// ```
+#[derive(Debug)]
+pub struct RetryBudget {
+    ratio: f64,
+    max_burst: f64,
+    balance: Mutex<f64>,
+}
+
+impl RetryBudget {
+    /// Create a full budget
+    ///
+    /// # Arguments
+    ///
+    /// * `ratio` - Retries allowed per request sent, e.g. `0.2` for one in five
+    /// * `max_burst` - Retries that may be spent at once, and the bucket's capacity
+    pub fn new(ratio: f64, max_burst: u32) -> Self {
+        Self {
+            ratio: ratio.max(0.0),
+            max_burst: f64::from(max_burst),
+            balance: Mutex::new(f64::from(max_burst)),
+        }
+    }
+
+    /// Record a request, earning `ratio` tokens up to the bucket's capacity
+    pub fn deposit(&self) {
+        let mut balance = self.balance.lock().unwrap_or_else(|e| e.into_inner());
+        *balance = (*balance + self.ratio).min(self.max_burst);
+    }
+
+    /// Spend a token for a retry, returning `false` if the budget is exhausted
+    pub fn try_withdraw(&self) -> bool {
+        let mut balance = self.balance.lock().unwrap_or_else(|e| e.into_inner());
+        if *balance >= 1.0 {
+            *balance -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for RetryBudget {
+    /// One retry per five requests, with bursts of up to ten
+    fn default() -> Self {
+        Self::new(0.2, 10)
+    }
+}
@@ -0,0 +1,163 @@
+//! Polling configuration for long-running operations
+//!
+//! Some Rain resources change state asynchronously. The `wait_for_*` helpers,
+//! `wait_for_card_active` for cards and `wait_for_shipped` for shipping groups,
+//! poll the API using a shared [`WaitConfig`] until the resource reaches the
+//! desired state, the timeout elapses or the attempt limit is reached.
+
+use crate::error::{RainError, Result};
+use std::time::{Duration, Instant};
+
+/// Configuration for polling helpers
+///
+/// Polling stops with [`RainError::Timeout`] once the next poll would start after
+/// `timeout`, or once `max_attempts` polls have been made.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::wait::WaitConfig;
+/// use std::time::Duration;
+///
+/// let config = WaitConfig::default();
+/// assert_eq!(config.interval, Duration::from_secs(5));
+/// assert_eq!(config.timeout, Duration::from_secs(300));
+/// assert_eq!(config.max_attempts, None);
+///
+/// let config = WaitConfig::new()
+///     .with_interval(Duration::from_secs(2))
+///     .with_timeout(Duration::from_secs(60))
+///     .with_max_attempts(10);
+/// assert_eq!(config.max_attempts, Some(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaitConfig {
+    /// Delay between consecutive polls
+    pub interval: Duration,
+    /// Maximum total time to wait before giving up
+    pub timeout: Duration,
+    /// Maximum number of polls before giving up, if limited
+    pub max_attempts: Option<usize>,
+}
+
+impl WaitConfig {
+    /// Create a configuration with the default interval and timeout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the delay between consecutive polls
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the maximum total time to wait
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of polls
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Decide whether to poll again after `attempts` polls and `elapsed` time
+    ///
+    /// Takes the elapsed time as an argument so callers (and tests) control the
+    /// clock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::client::wait::WaitConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = WaitConfig::new()
+    ///     .with_interval(Duration::from_secs(5))
+    ///     .with_timeout(Duration::from_secs(20));
+    ///
+    /// // The next poll would start at 15s, within the timeout
+    /// assert!(config.should_retry(2, Duration::from_secs(10)));
+    /// // The next poll would start at 21s, past the timeout
+    /// assert!(!config.should_retry(3, Duration::from_secs(16)));
+    ///
+    /// let config = config.with_max_attempts(3);
+    /// assert!(config.should_retry(2, Duration::from_secs(10)));
+    /// assert!(!config.should_retry(3, Duration::from_secs(10)));
+    /// ```
+    pub fn should_retry(&self, attempts: usize, elapsed: Duration) -> bool {
+        let attempts_left = self.max_attempts.is_none_or(|max| attempts < max);
+        attempts_left && elapsed + self.interval <= self.timeout
+    }
+}
+
+impl Default for WaitConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(300),
+            max_attempts: None,
+        }
+    }
+}
+
+/// Poll `fetch` until `done` returns `true` or the timeout elapses
+#[cfg(feature = "async")]
+pub(crate) async fn poll_until<T, F, Fut>(
+    config: &WaitConfig,
+    what: &str,
+    mut fetch: F,
+    done: impl Fn(&T) -> Result<bool>,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let started = Instant::now();
+    let mut attempts = 0;
+    loop {
+        let value = fetch().await?;
+        attempts += 1;
+        if done(&value)? {
+            return Ok(value);
+        }
+        if !config.should_retry(attempts, started.elapsed()) {
+            return Err(timeout_error(config, what, attempts));
+        }
+        tokio::time::sleep(config.interval).await;
+    }
+}
+
+/// Poll `fetch` until `done` returns `true` or the timeout elapses (blocking)
+#[cfg(feature = "sync")]
+pub(crate) fn poll_until_blocking<T>(
+    config: &WaitConfig,
+    what: &str,
+    mut fetch: impl FnMut() -> Result<T>,
+    done: impl Fn(&T) -> Result<bool>,
+) -> Result<T> {
+    let started = Instant::now();
+    let mut attempts = 0;
+    loop {
+        let value = fetch()?;
+        attempts += 1;
+        if done(&value)? {
+            return Ok(value);
+        }
+        if !config.should_retry(attempts, started.elapsed()) {
+            return Err(timeout_error(config, what, attempts));
+        }
+        std::thread::sleep(config.interval);
+    }
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+fn timeout_error(config: &WaitConfig, what: &str, attempts: usize) -> RainError {
+    RainError::Timeout(format!(
+        "{what} did not complete within {}s ({attempts} attempts)",
+        config.timeout.as_secs()
+    ))
+}
@@ -0,0 +1,46 @@
+//! Time sources for clock-sensitive checks
+//!
+//! Webhook timestamp checks and anything else that compares against "now" read
+//! the time through the [`Clock`] trait. The default [`SystemClock`] uses the
+//! host clock; an NTP-synced source can be plugged in with
+//! [`Config::with_clock`](crate::Config::with_clock), and tests can pin the time
+//! with [`FixedClock`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use chrono::{TimeZone, Utc};
+//! use rain_sdk::clock::{Clock, FixedClock};
+//!
+//! let instant = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+//! assert_eq!(FixedClock(instant).now(), instant);
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// A source of the current time
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Get the current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the host's system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
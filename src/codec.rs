@@ -0,0 +1,73 @@
+//! Hooks for custom request and response body encoding
+//!
+//! By default the client writes request bodies and reads responses with
+//! `serde_json`. A gateway that transforms bodies in a non-standard way, such as
+//! wrapping them in an envelope, can be supported by implementing [`BodyCodec`] and
+//! plugging it in with [`Config::with_codec`](crate::Config::with_codec).
+//!
+//! A codec works on the JSON form of a body: the client serializes the request to
+//! a [`serde_json::Value`] before handing it to [`BodyCodec::encode`], and
+//! deserializes the value returned by [`BodyCodec::decode`] into the response type.
+//! Error responses go through [`BodyCodec::decode`] too, and are read as plain
+//! JSON if the codec fails on them. Raw downloads such as
+//! [`RainClient::get_bytes`](crate::RainClient::get_bytes) are returned undecoded.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rain_sdk::codec::BodyCodec;
+//! use rain_sdk::{Config, Environment, RainError, Result};
+//! use serde_json::{json, Value};
+//!
+//! /// Wraps every body in `{"data": ...}`
+//! #[derive(Debug)]
+//! struct EnvelopeCodec;
+//!
+//! impl BodyCodec for EnvelopeCodec {
+//!     fn encode(&self, value: Value) -> Result<Vec<u8>> {
+//!         Ok(serde_json::to_vec(&json!({ "data": value }))?)
+//!     }
+//!
+//!     fn decode(&self, body: &[u8]) -> Result<Value> {
+//!         let mut envelope: Value = serde_json::from_slice(body)?;
+//!         match envelope.get_mut("data") {
+//!             Some(data) => Ok(data.take()),
+//!             None => Err(RainError::ValidationError("missing envelope".to_string())),
+//!         }
+//!     }
+//! }
+//!
+//! let config = Config::new(Environment::Dev).with_codec(EnvelopeCodec);
+//! ```
+
+use crate::error::Result;
+use serde_json::Value;
+use std::fmt;
+
+/// Encoding of request bodies and decoding of response bodies
+pub trait BodyCodec: fmt::Debug + Send + Sync {
+    /// Turn a serialized request body into the bytes sent on the wire
+    fn encode(&self, value: Value) -> Result<Vec<u8>>;
+
+    /// Turn the bytes of a response into the value to deserialize
+    ///
+    /// Called for error responses as well, whose value is read as an
+    /// [`ApiErrorResponse`](crate::error::ApiErrorResponse).
+    fn decode(&self, body: &[u8]) -> Result<Value>;
+}
+
+/// Codec reading and writing bodies as plain JSON
+///
+/// Equivalent to the client's default behaviour.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl BodyCodec for JsonCodec {
+    fn encode(&self, value: Value) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&value)?)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Value> {
+        Ok(serde_json::from_slice(body)?)
+    }
+}
@@ -24,6 +24,13 @@
 //!     .with_logging(true);
 //! ```
 
+use crate::clock::{Clock, SystemClock};
+use crate::codec::BodyCodec;
+use crate::error::RainError;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 
 /// Environment configuration for the Rain API
@@ -47,7 +54,10 @@ use url::Url;
 /// ```
 #[derive(Debug, Clone, Default)]
 pub enum Environment {
-    /// Dev environment
+    /// Dev environment, Rain's sandbox for testing
+    ///
+    /// Rain has no separate staging tier, so this is also the environment to use
+    /// for staging deployments.
     #[default]
     Dev,
     /// Production environment
@@ -81,6 +91,93 @@ impl Environment {
             Environment::Custom(url) => url.clone(),
         }
     }
+
+    /// Custom environment that serves the API under the same `/v1/issuing`
+    /// prefix as Dev and Production
+    ///
+    /// Use this to point the client at a locally running mock: only the scheme,
+    /// host and port need to be given, and every endpoint resolves as it would
+    /// against the hosted API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::Environment;
+    ///
+    /// let env = Environment::custom_dev_like(url::Url::parse("http://localhost:8080").unwrap());
+    /// assert_eq!(env.base_url().as_str(), "http://localhost:8080/v1/issuing");
+    /// ```
+    pub fn custom_dev_like(mut base: Url) -> Self {
+        if let Ok(mut segments) = base.path_segments_mut() {
+            segments.pop_if_empty().extend(["v1", "issuing"]);
+        }
+        Environment::Custom(base)
+    }
+}
+
+impl FromStr for Environment {
+    type Err = RainError;
+
+    /// Parse `"dev"` (or `"sandbox"`), `"production"` (or `"prod"`), or a URL for a
+    /// custom endpoint
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::Environment;
+    ///
+    /// assert!(matches!("dev".parse::<Environment>().unwrap(), Environment::Dev));
+    /// let sandbox: Environment = "Sandbox".parse().unwrap();
+    /// assert_eq!(sandbox.base_url().as_str(), "https://api-dev.raincards.xyz/v1/issuing");
+    /// assert_eq!(sandbox.to_string(), "dev");
+    /// assert!(matches!("production".parse::<Environment>().unwrap(), Environment::Production));
+    /// assert!(matches!("PROD".parse::<Environment>().unwrap(), Environment::Production));
+    ///
+    /// let custom: Environment = "https://api.example.com/v1".parse().unwrap();
+    /// assert_eq!(custom.base_url().as_str(), "https://api.example.com/v1");
+    ///
+    /// // Rain has no staging tier; the error points at dev instead
+    /// let err = "staging".parse::<Environment>().unwrap_err();
+    /// assert!(err.to_string().contains("use \"dev\""));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "dev" | "sandbox" => Ok(Environment::Dev),
+            "production" | "prod" => Ok(Environment::Production),
+            "staging" | "stage" => Err(RainError::ValidationError(format!(
+                "Unknown environment {s:?}: Rain has no staging tier, use \"dev\" for testing"
+            ))),
+            _ => Url::parse(s).map(Environment::Custom).map_err(|_| {
+                RainError::ValidationError(format!(
+                    "Unknown environment {s:?}: expected \"dev\", \"production\" or a URL"
+                ))
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    /// Format as the string accepted by [`FromStr`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::Environment;
+    ///
+    /// assert_eq!(Environment::Dev.to_string(), "dev");
+    /// assert_eq!(Environment::Production.to_string(), "production");
+    ///
+    /// let custom: Environment = "https://api.example.com/v1".parse().unwrap();
+    /// assert_eq!(custom.to_string(), "https://api.example.com/v1");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Environment::Dev => write!(f, "dev"),
+            Environment::Production => write!(f, "production"),
+            Environment::Custom(url) => write!(f, "{url}"),
+        }
+    }
 }
 
 /// Client configuration
@@ -108,10 +205,32 @@ pub struct Config {
     pub base_url: Url,
     /// Request timeout in seconds
     pub timeout_secs: u64,
+    /// Maximum time to establish a connection, if set
+    pub connect_timeout: Option<Duration>,
+    /// Maximum time between reads of the response, if set
+    pub read_timeout: Option<Duration>,
     /// User agent string
     pub user_agent: String,
     /// Enable request/response logging
     pub enable_logging: bool,
+    /// Force HTTP/2 without ALPN negotiation
+    pub http2_prior_knowledge: bool,
+    /// Redirects followed before a request fails; redirects to another host always fail
+    pub max_redirects: usize,
+    /// Extra headers sent with every request
+    pub default_headers: Vec<(String, String)>,
+    /// Preferred language for responses, sent as `Accept-Language`
+    pub locale: Option<String>,
+    /// API version to pin, sent as `Rain-Version`
+    pub api_version: Option<String>,
+    /// Source of the current time for clock-sensitive checks
+    pub clock: Arc<dyn Clock>,
+    /// Retries allowed per request sent
+    pub retry_ratio: f64,
+    /// Retries that may be spent at once
+    pub retry_max_burst: u32,
+    /// Custom encoding of request and response bodies, plain JSON if unset
+    pub codec: Option<Arc<dyn BodyCodec>>,
 }
 
 impl Config {
@@ -120,8 +239,16 @@ impl Config {
     /// Creates a configuration with:
     /// - Base URL from the environment
     /// - 30 second timeout
-    /// - Default user agent (includes SDK version)
+    /// - Default user agent: `rain-sdk/{version} (rust/{rustc}; {os}; {arch})`
     /// - Logging disabled
+    /// - No separate connect or read timeout
+    /// - No `Accept-Language` header
+    /// - No `Rain-Version` header, so Rain's default API version is used
+    /// - The system clock as the time source
+    /// - A retry budget of one retry per five requests, in bursts of up to ten
+    /// - HTTP version negotiated with the server
+    /// - Up to 3 redirects followed, all on the same host
+    /// - Bodies read and written as plain JSON
     ///
     /// # Arguments
     ///
@@ -138,8 +265,19 @@ impl Config {
         Self {
             base_url: environment.base_url(),
             timeout_secs: 30,
-            user_agent: format!("rain-sdk/{}", env!("CARGO_PKG_VERSION")),
+            connect_timeout: None,
+            read_timeout: None,
+            user_agent: default_user_agent(),
             enable_logging: false,
+            http2_prior_knowledge: false,
+            max_redirects: 3,
+            default_headers: Vec::new(),
+            locale: None,
+            api_version: None,
+            clock: Arc::new(SystemClock),
+            retry_ratio: 0.2,
+            retry_max_burst: 10,
+            codec: None,
         }
     }
 
@@ -162,19 +300,90 @@ impl Config {
         self
     }
 
+    /// Set the connect timeout
+    ///
+    /// Bounds DNS resolution and connection setup separately from the overall
+    /// request timeout, so an unreachable host fails fast without cutting short
+    /// a slow but healthy response.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to establish a connection
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let config = Config::new(Environment::Custom(
+    ///     url::Url::parse("http://10.255.255.1/v1").unwrap(),
+    /// ))
+    /// .with_timeout(60)
+    /// .with_connect_timeout(Duration::from_millis(500));
+    /// assert_eq!(config.connect_timeout, Some(Duration::from_millis(500)));
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # tokio_test::block_on(async {
+    /// let client = RainClient::new(config, AuthConfig::with_api_key("key".to_string())).unwrap();
+    /// let started = Instant::now();
+    /// assert!(client.server_time().await.is_err());
+    /// assert!(started.elapsed() < Duration::from_secs(10));
+    /// # });
+    /// ```
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the read timeout
+    ///
+    /// Applies to each read of the response rather than the whole request, so a
+    /// long download keeps going as long as data arrives. The blocking client
+    /// cannot time out individual reads, so it uses this as its request timeout
+    /// when it is shorter than [`timeout_secs`](Self::timeout_secs).
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait for the next chunk of the response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rain_sdk::{Config, Environment};
+    /// use std::time::Duration;
+    ///
+    /// let config = Config::new(Environment::Dev)
+    ///     .with_read_timeout(Duration::from_secs(15));
+    /// ```
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
     /// Set a custom user agent string
     ///
+    /// Replaces the default user agent entirely, including the platform details.
+    ///
     /// # Arguments
     ///
     /// * `user_agent` - Custom user agent string
     ///
     /// # Examples
     ///
-    /// ```no_run
+    /// ```rust
     /// use rain_sdk::{Config, Environment};
     ///
+    /// let config = Config::new(Environment::Dev);
+    /// assert!(config
+    ///     .user_agent
+    ///     .starts_with(&format!("rain-sdk/{} (rust/", env!("CARGO_PKG_VERSION"))));
+    /// assert!(config.user_agent.contains(std::env::consts::OS));
+    /// assert!(config.user_agent.contains(std::env::consts::ARCH));
+    ///
     /// let config = Config::new(Environment::Dev)
     ///     .with_user_agent("my-app/1.0".to_string());
+    /// assert_eq!(config.user_agent, "my-app/1.0");
     /// ```
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
         self.user_agent = user_agent;
@@ -183,6 +392,11 @@ impl Config {
 
     /// Enable or disable request/response logging
     ///
+    /// When enabled, a 4xx response to a request with a JSON body includes the
+    /// pretty-printed request body in the error, with credentials such as
+    /// passwords and API keys and personal details such as emails, phone
+    /// numbers, national IDs, birth dates and addresses redacted.
+    ///
     /// # Arguments
     ///
     /// * `enable` - Whether to enable logging
@@ -195,10 +409,226 @@ impl Config {
     /// let config = Config::new(Environment::Dev)
     ///     .with_logging(true);
     /// ```
+    ///
+    /// A rejected request shows the body that was sent:
+    ///
+    /// ```rust
+    /// use rain_sdk::{Config, Environment};
+    ///
+    /// let config = Config::new(Environment::Dev).with_logging(true);
+    /// assert!(config.enable_logging);
+    /// ```
     pub fn with_logging(mut self, enable: bool) -> Self {
         self.enable_logging = enable;
         self
     }
+
+    /// Force HTTP/2 with prior knowledge
+    ///
+    /// When enabled, the client speaks HTTP/2 immediately instead of negotiating
+    /// the protocol. Only use this when every hop to the API is known to support
+    /// HTTP/2, such as behind a gateway that requires it.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to force HTTP/2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{Config, Environment};
+    ///
+    /// let config = Config::new(Environment::Dev).with_http2_prior_knowledge(true);
+    /// assert!(config.http2_prior_knowledge);
+    /// ```
+    pub fn with_http2_prior_knowledge(mut self, enable: bool) -> Self {
+        self.http2_prior_knowledge = enable;
+        self
+    }
+
+    /// Set the preferred language for API responses
+    ///
+    /// Sent as the `Accept-Language` header on every request, including file
+    /// uploads, so the API can localize error messages where supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - A language tag such as `"en-US"` or `"fr"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{Config, Environment};
+    ///
+    /// let config = Config::new(Environment::Dev).with_locale("fr-FR");
+    /// assert_eq!(config.locale.as_deref(), Some("fr-FR"));
+    /// ```
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Pin the Rain API version
+    ///
+    /// Sends the `Rain-Version` header with every request, including multipart
+    /// uploads, so a change to Rain's default version does not change the responses
+    /// the client receives. Without it no version header is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The API version, such as `"2024-01-01"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{Config, Environment};
+    ///
+    /// let config = Config::new(Environment::Dev).with_api_version("2024-01-01");
+    /// assert_eq!(config.api_version.as_deref(), Some("2024-01-01"));
+    /// ```
+    pub fn with_api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    /// Set the clock used for clock-sensitive checks
+    ///
+    /// Defaults to [`SystemClock`]. Plug in an NTP-synced source on hosts whose
+    /// clock drifts, or a [`FixedClock`](crate::clock::FixedClock) in tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The time source
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use rain_sdk::clock::FixedClock;
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    ///
+    /// let instant = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    /// let config = Config::new(Environment::Dev).with_clock(FixedClock(instant));
+    /// let client = RainClient::new(config, AuthConfig::with_api_key("key".to_string())).unwrap();
+    /// assert_eq!(client.now(), instant);
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set the client's retry budget
+    ///
+    /// Retries performed by the SDK, such as re-reading the secrets of a new card in
+    /// [`create_virtual_card_with_secrets`](crate::RainClient::create_virtual_card_with_secrets),
+    /// are dropped once they exceed `ratio` of the attempts made by those retry loops,
+    /// after an initial allowance of `max_burst`. See [`RetryBudget`](crate::client::retry::RetryBudget).
+    ///
+    /// # Arguments
+    ///
+    /// * `ratio` - Retries allowed per request sent, e.g. `0.1` for one in ten
+    /// * `max_burst` - Retries that may be spent at once
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    ///
+    /// let config = Config::new(Environment::Dev).with_retry_budget(0.5, 1);
+    /// let client = RainClient::new(config, AuthConfig::with_api_key("key".to_string())).unwrap();
+    /// assert!(client.retry_budget().try_withdraw());
+    /// assert!(!client.retry_budget().try_withdraw());
+    /// ```
+    pub fn with_retry_budget(mut self, ratio: f64, max_burst: u32) -> Self {
+        self.retry_ratio = ratio;
+        self.retry_max_burst = max_burst;
+        self
+    }
+
+    /// Set a custom codec for request and response bodies
+    ///
+    /// See the [`codec`](crate::codec) module for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - Encoding applied to request bodies and response bodies, including errors
+    pub fn with_codec(mut self, codec: impl BodyCodec + 'static) -> Self {
+        self.codec = Some(Arc::new(codec));
+        self
+    }
+
+    /// Set how many redirects a request may follow
+    ///
+    /// Defaults to 3, and `0` turns redirects into errors. Whatever the limit, a
+    /// redirect to a different host or port fails with a
+    /// [`RainError::HttpError`](crate::RainError::HttpError), so the API key is never
+    /// sent to an unexpected domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_redirects` - Redirects followed before the request fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{Config, Environment};
+    ///
+    /// let config = Config::new(Environment::Dev).with_max_redirects(1);
+    /// assert_eq!(config.max_redirects, 1);
+    /// ```
+    pub fn with_max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Add a header to send with every request
+    ///
+    /// Can be called repeatedly to add several headers. Setting the same header
+    /// name twice keeps the last value. Names and values are validated when the
+    /// client is created, so [`RainClient::new`](crate::RainClient::new) returns an
+    /// error for an invalid header.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Header name
+    /// * `value` - Header value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+    ///
+    /// let config = Config::new(Environment::Dev)
+    ///     .with_default_header("X-Tenant-Id", "tenant-123")
+    ///     .with_default_header("X-Env", "staging");
+    /// assert_eq!(config.default_headers.len(), 2);
+    ///
+    /// # #[cfg(feature = "async")]
+    /// # {
+    /// let invalid = Config::new(Environment::Dev).with_default_header("Bad Header", "value");
+    /// let auth = AuthConfig::with_api_key("your-api-key".to_string());
+    /// assert!(RainClient::new(invalid, auth).is_err());
+    /// # }
+    /// ```
+    pub fn with_default_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Build the default user agent, identifying the SDK version and platform
+fn default_user_agent() -> String {
+    format!(
+        "rain-sdk/{} (rust/{}; {}; {})",
+        env!("CARGO_PKG_VERSION"),
+        env!("RAIN_SDK_RUSTC_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    )
 }
 
 impl Default for Config {
@@ -11,7 +11,10 @@
 //! - `401` - Invalid authorization / Unauthorized (missing or invalid API key)
 //! - `403` - Forbidden (insufficient permissions)
 //! - `404` - Not found (resource not found: User, Card, Company, Transaction, Team, etc.)
-//! - `409` - Conflict (e.g., "Company already has a contract on this chain", "User already has a contract on this chain", "Another active signature already exists")
+//! - `409` - Conflict (e.g., "Company already has a contract on this chain", "User already has a contract on this chain", "Another active signature already exists"), returned as
+//!   [`RainError::Conflict`] with a typed [`ConflictReason`]
+//! - `412` - Precondition failed (an `If-Match` ETag no longer matches), returned as
+//!   [`RainError::PreconditionFailed`]
 //! - `423` - Locked (e.g., "User address is locked")
 //! - `500` - Internal server error
 
@@ -20,6 +23,7 @@ use std::fmt;
 
 /// Main error type for the Rain SDK
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum RainError {
     /// HTTP client errors
     #[error("HTTP error: {0}")]
@@ -44,14 +48,79 @@ pub enum RainError {
         response: Box<ApiErrorResponse>,
     },
 
+    /// Conflict with the current state of a resource (HTTP 409)
+    ///
+    /// Returned instead of [`RainError::ApiError`] for 409 responses so callers can
+    /// branch on the documented reasons, e.g. cancel an existing signature.
+    #[error("Conflict: {reason}")]
+    Conflict {
+        /// Why the request conflicted
+        reason: ConflictReason,
+        /// Error response details
+        response: Box<ApiErrorResponse>,
+    },
+
+    /// The resource changed since its ETag was read (HTTP 412)
+    ///
+    /// Returned by conditional updates such as
+    /// [`RainClient::update_card_if_match`](crate::RainClient::update_card_if_match).
+    /// Re-read the resource and retry the update against the fresh state.
+    #[error("Precondition failed: {response}")]
+    PreconditionFailed {
+        /// Error response details
+        response: Box<ApiErrorResponse>,
+    },
+
     /// Authentication errors
     #[error("Authentication error: {0}")]
     AuthError(String),
 
+    /// The API key was rejected (HTTP 401)
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// The API key lacks permission for the resource (HTTP 403)
+    ///
+    /// Returned by permission-sensitive operations such as
+    /// [`RainClient::delete_key`](crate::RainClient::delete_key).
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    /// The resource does not exist (HTTP 404)
+    ///
+    /// Returned by operations such as
+    /// [`RainClient::delete_key`](crate::RainClient::delete_key) where a missing
+    /// resource is worth branching on.
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     /// Request validation errors
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// The requested operation is not supported by the Rain API
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
+
+    /// A polling operation did not complete in time
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
+    /// The request was aborted through its cancellation token, or abandoned by a
+    /// [`BulkMode::FailFast`](crate::client::bulk::BulkMode::FailFast) batch
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// Query parameters could not be encoded into a URL query string
+    #[error("Failed to encode {params_type} as query parameters: {source}")]
+    QuerySerialization {
+        /// Name of the parameters type that failed to encode
+        params_type: &'static str,
+        /// The underlying encoding error
+        #[source]
+        source: serde_urlencoded::ser::Error,
+    },
+
     /// JSON deserialization errors
     #[error("Deserialization error: {0}")]
     DeserializationError(#[from] serde_json::Error),
@@ -61,11 +130,208 @@ pub enum RainError {
     Other(#[from] anyhow::Error),
 }
 
+/// Documented reasons for a 409 Conflict response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictReason {
+    /// Another active signature already exists
+    ActiveSignatureExists,
+    /// The company already has a contract on this chain
+    CompanyContractExists,
+    /// The user already has a contract on this chain
+    UserContractExists,
+    /// The dispute is resolved or canceled and can no longer be edited
+    ///
+    /// Detected client-side by the `_if_editable` dispute methods.
+    DisputeNotEditable,
+    /// Any other conflict, with the server's message
+    Other(String),
+}
+
+impl ConflictReason {
+    /// Classify a conflict from the error message returned by the API
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::error::ConflictReason;
+    ///
+    /// assert_eq!(
+    ///     ConflictReason::from_message("Another active signature already exists"),
+    ///     ConflictReason::ActiveSignatureExists
+    /// );
+    /// assert_eq!(
+    ///     ConflictReason::from_message("Company already has a contract on this chain"),
+    ///     ConflictReason::CompanyContractExists
+    /// );
+    /// assert_eq!(
+    ///     ConflictReason::from_message("User already has a contract on this chain"),
+    ///     ConflictReason::UserContractExists
+    /// );
+    /// assert_eq!(
+    ///     ConflictReason::from_message("Something else"),
+    ///     ConflictReason::Other("Something else".to_string())
+    /// );
+    /// ```
+    pub fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("active signature") {
+            ConflictReason::ActiveSignatureExists
+        } else if lower.contains("already has a contract") && lower.contains("company") {
+            ConflictReason::CompanyContractExists
+        } else if lower.contains("already has a contract") && lower.contains("user") {
+            ConflictReason::UserContractExists
+        } else {
+            ConflictReason::Other(message.to_string())
+        }
+    }
+}
+
+impl fmt::Display for ConflictReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictReason::ActiveSignatureExists => {
+                write!(f, "Another active signature already exists")
+            }
+            ConflictReason::CompanyContractExists => {
+                write!(f, "Company already has a contract on this chain")
+            }
+            ConflictReason::UserContractExists => {
+                write!(f, "User already has a contract on this chain")
+            }
+            ConflictReason::DisputeNotEditable => {
+                write!(f, "Dispute can no longer be edited")
+            }
+            ConflictReason::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Error codes returned by the Rain API in the `code` field of an error body
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::error::RainErrorCode;
+///
+/// for (code, wire) in [
+///     (RainErrorCode::InsufficientCollateral, "\"insufficient_collateral\""),
+///     (RainErrorCode::KycRequired, "\"kyc_required\""),
+///     (RainErrorCode::CardNotActive, "\"card_not_active\""),
+///     (RainErrorCode::RateLimited, "\"rate_limited\""),
+/// ] {
+///     assert_eq!(serde_json::from_str::<RainErrorCode>(wire).unwrap(), code);
+///     assert_eq!(serde_json::to_string(&code).unwrap(), wire);
+/// }
+///
+/// let unknown: RainErrorCode = serde_json::from_str("\"brand_new_code\"").unwrap();
+/// assert_eq!(unknown, RainErrorCode::Unknown("brand_new_code".to_string()));
+/// assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"brand_new_code\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RainErrorCode {
+    /// The collateral balance does not cover the operation
+    InsufficientCollateral,
+    /// The user must complete KYC first
+    KycRequired,
+    /// The card is not in an active state
+    CardNotActive,
+    /// The user is locked
+    UserLocked,
+    /// The request body or parameters were invalid
+    InvalidRequest,
+    /// The resource does not exist
+    NotFound,
+    /// Too many requests were sent
+    RateLimited,
+    /// A code the SDK does not model yet
+    Unknown(String),
+}
+
+impl RainErrorCode {
+    /// Wire representation of the code
+    pub fn as_str(&self) -> &str {
+        match self {
+            RainErrorCode::InsufficientCollateral => "insufficient_collateral",
+            RainErrorCode::KycRequired => "kyc_required",
+            RainErrorCode::CardNotActive => "card_not_active",
+            RainErrorCode::UserLocked => "user_locked",
+            RainErrorCode::InvalidRequest => "invalid_request",
+            RainErrorCode::NotFound => "not_found",
+            RainErrorCode::RateLimited => "rate_limited",
+            RainErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<String> for RainErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "insufficient_collateral" => RainErrorCode::InsufficientCollateral,
+            "kyc_required" => RainErrorCode::KycRequired,
+            "card_not_active" => RainErrorCode::CardNotActive,
+            "user_locked" => RainErrorCode::UserLocked,
+            "invalid_request" => RainErrorCode::InvalidRequest,
+            "not_found" => RainErrorCode::NotFound,
+            "rate_limited" => RainErrorCode::RateLimited,
+            _ => RainErrorCode::Unknown(code),
+        }
+    }
+}
+
+impl From<RainErrorCode> for String {
+    fn from(code: RainErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl fmt::Display for RainErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// API error response structure
 ///
 /// This structure represents error responses from the Rain API.
 /// The API may return different error formats, so all fields are optional.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Besides a single error object, the body may be a list of errors, either bare
+/// or wrapped as `{"errors": [...]}`, whose entries are objects or plain strings.
+/// Every entry is kept in `errors`, and `message`, `code` and `details` come from
+/// the envelope when it sets them, or else from the first entry.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::error::ApiErrorResponse;
+///
+/// let single: ApiErrorResponse =
+///     serde_json::from_str(r#"{"message":"Invalid email","code":"invalid_request"}"#).unwrap();
+/// assert_eq!(single.message.as_deref(), Some("Invalid email"));
+/// assert!(single.errors.is_empty());
+///
+/// let list: ApiErrorResponse = serde_json::from_str(
+///     r#"[{"message":"Invalid email","code":"invalid_request"},{"message":"Missing name"}]"#,
+/// )
+/// .unwrap();
+/// assert_eq!(list.message.as_deref(), Some("Invalid email"));
+/// assert_eq!(list.code.as_deref(), Some("invalid_request"));
+/// assert_eq!(list.errors.len(), 2);
+/// assert_eq!(list.errors[1].message.as_deref(), Some("Missing name"));
+///
+/// let envelope: ApiErrorResponse = serde_json::from_str(
+///     r#"{"message":"Validation failed","errors":["Invalid email",{"message":"Missing name","details":{"field":"name"}}]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(envelope.message.as_deref(), Some("Validation failed"));
+/// assert_eq!(envelope.errors[0].message.as_deref(), Some("Invalid email"));
+/// assert_eq!(envelope.errors[1].details, Some(serde_json::json!({"field": "name"})));
+///
+/// let empty: ApiErrorResponse = serde_json::from_str(r#"{"errors":[]}"#).unwrap();
+/// assert!(empty.message.is_none() && empty.errors.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize)]
 pub struct ApiErrorResponse {
     /// Error message describing what went wrong
     pub message: Option<String>,
@@ -75,6 +341,14 @@ pub struct ApiErrorResponse {
 
     /// Additional error details (structured data)
     pub details: Option<serde_json::Value>,
+
+    /// Each error, when the body listed several
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorResponse>,
+
+    /// The redacted request body that triggered a 4xx error, when logging is enabled
+    #[serde(skip)]
+    pub request_body: Option<String>,
 }
 
 impl ApiErrorResponse {
@@ -84,16 +358,96 @@ impl ApiErrorResponse {
             message: Some(message),
             code: None,
             details: None,
+            errors: Vec::new(),
+            request_body: None,
         }
     }
 
+    /// Get the typed error code, if the response included one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::error::{ApiErrorResponse, RainErrorCode};
+    ///
+    /// let body: ApiErrorResponse =
+    ///     serde_json::from_str(r#"{"message":"Not enough collateral","code":"insufficient_collateral"}"#)
+    ///         .unwrap();
+    /// assert_eq!(body.code(), Some(RainErrorCode::InsufficientCollateral));
+    /// ```
+    pub fn code(&self) -> Option<RainErrorCode> {
+        self.code.clone().map(RainErrorCode::from)
+    }
+
     /// Create a new API error response with a message and code
     pub fn with_code(message: String, code: String) -> Self {
         Self {
             message: Some(message),
             code: Some(code),
             details: None,
+            errors: Vec::new(),
+            request_body: None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiErrorResponse {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize, Default)]
+        struct Single {
+            message: Option<String>,
+            code: Option<String>,
+            details: Option<serde_json::Value>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Entry {
+            Text(String),
+            Single(Single),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Shape {
+            List(Vec<Entry>),
+            Envelope {
+                errors: Vec<Entry>,
+                #[serde(flatten)]
+                top: Single,
+            },
+            Single(Single),
         }
+
+        let from_single = |single: Single| ApiErrorResponse {
+            message: single.message,
+            code: single.code,
+            details: single.details,
+            errors: Vec::new(),
+            request_body: None,
+        };
+        let (top, entries) = match Shape::deserialize(deserializer)? {
+            Shape::Single(single) => return Ok(from_single(single)),
+            Shape::List(entries) => (Single::default(), entries),
+            Shape::Envelope { errors, top } => (top, errors),
+        };
+        let errors: Vec<_> = entries
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Text(message) => ApiErrorResponse::new(message),
+                Entry::Single(single) => from_single(single),
+            })
+            .collect();
+        let first = errors.first();
+        Ok(ApiErrorResponse {
+            message: top.message.or_else(|| first?.message.clone()),
+            code: top.code.or_else(|| first?.code.clone()),
+            details: top.details.or_else(|| first?.details.clone()),
+            errors,
+            request_body: None,
+        })
     }
 }
 
@@ -106,6 +460,9 @@ impl fmt::Display for ApiErrorResponse {
         } else {
             write!(f, "API error")?;
         }
+        if let Some(ref body) = self.request_body {
+            write!(f, "\nRequest body:\n{body}")?;
+        }
         Ok(())
     }
 }
@@ -122,6 +479,41 @@ impl From<ApiErrorResponse> for RainError {
     }
 }
 
+impl RainError {
+    /// Build the error for a non-success response with a parsed error body
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::error::{ApiErrorResponse, ConflictReason, RainError};
+    ///
+    /// let body = ApiErrorResponse::new("Another active signature already exists".to_string());
+    /// let err = RainError::from_api_response(409, body);
+    /// assert!(matches!(
+    ///     err,
+    ///     RainError::Conflict { reason: ConflictReason::ActiveSignatureExists, .. }
+    /// ));
+    ///
+    /// let err = RainError::from_api_response(400, ApiErrorResponse::new("Bad".to_string()));
+    /// assert!(matches!(err, RainError::ApiError { status: 400, .. }));
+    /// ```
+    pub fn from_api_response(status: u16, response: ApiErrorResponse) -> Self {
+        match status {
+            409 => RainError::Conflict {
+                reason: ConflictReason::from_message(&response.to_string()),
+                response: Box::new(response),
+            },
+            412 => RainError::PreconditionFailed {
+                response: Box::new(response),
+            },
+            _ => RainError::ApiError {
+                status,
+                response: Box::new(response),
+            },
+        }
+    }
+}
+
 /// Result type alias for Rain SDK operations
 pub type Result<T> = std::result::Result<T, RainError>;
 
@@ -29,20 +29,36 @@
 //!
 //! See the [documentation](https://docs.rs/rain-sdk) for detailed API reference.
 
+#[cfg(all(
+    any(feature = "async", feature = "sync"),
+    not(any(feature = "rustls-tls", feature = "native-tls"))
+))]
+compile_error!(
+    "rain-sdk needs a TLS backend to reach the API over HTTPS: enable the `rustls-tls` or `native-tls` feature"
+);
+
 pub mod api;
 pub mod auth;
 pub mod client;
+pub mod clock;
+pub mod codec;
 pub mod config;
 pub mod error;
 pub mod models;
+pub mod prelude;
+#[cfg(feature = "validate-schema")]
+mod schema;
 
 pub use auth::AuthConfig;
 pub use client::RainClient;
+pub use clock::{Clock, SystemClock};
 pub use config::{Config, Environment};
 pub use error::{RainError, Result};
+#[cfg(feature = "async")]
+pub use tokio_util::sync::CancellationToken;
 
 // Re-export API modules
 pub use api::{
-    applications, balances, cards, companies, contracts, disputes, keys, payments, reports,
-    shipping_groups, signatures, subtenants, transactions, users, webhooks,
+    applications, balances, cards, companies, contracts, disputes, keys, metadata, payments,
+    reports, shipping_groups, signatures, subtenants, transactions, users, webhooks,
 };
@@ -2,6 +2,7 @@
 
 use crate::models::common::*;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use uuid::Uuid;
 
 // ============================================================================
@@ -74,11 +75,42 @@ pub struct EntityInfo {
 }
 
 /// Entity update information (all fields optional for updates)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields left as `None` are not sent and stay unchanged. The optional entity
+/// fields, `type` and `expectedSpend`, can also be cleared with `Some(None)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::applications::EntityUpdateInfo;
+///
+/// let entity = EntityUpdateInfo::builder()
+///     .website("https://example.com")
+///     .build();
+/// assert_eq!(
+///     serde_json::to_value(&entity).unwrap(),
+///     serde_json::json!({"website": "https://example.com"})
+/// );
+///
+/// let entity = EntityUpdateInfo::builder()
+///     .industry("Software")
+///     .clear_expected_spend()
+///     .build();
+/// assert_eq!(
+///     serde_json::to_value(&entity).unwrap(),
+///     serde_json::json!({"industry": "Software", "expectedSpend": null})
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityUpdateInfo {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<String>,
+    /// `None` leaves the type unchanged, `Some(None)` clears it
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::models::common::double_option"
+    )]
+    pub r#type: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,8 +121,87 @@ pub struct EntityUpdateInfo {
     pub tax_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub website: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expected_spend: Option<String>,
+    /// `None` leaves the expected spend unchanged, `Some(None)` clears it
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::models::common::double_option"
+    )]
+    pub expected_spend: Option<Option<String>>,
+}
+
+impl EntityUpdateInfo {
+    /// Create a builder that only sets the fields being changed
+    pub fn builder() -> EntityUpdateInfoBuilder {
+        EntityUpdateInfoBuilder::default()
+    }
+}
+
+/// Builder for [`EntityUpdateInfo`]
+#[derive(Debug, Clone, Default)]
+pub struct EntityUpdateInfoBuilder {
+    entity: EntityUpdateInfo,
+}
+
+impl EntityUpdateInfoBuilder {
+    /// Set the entity type
+    pub fn r#type(mut self, entity_type: impl Into<String>) -> Self {
+        self.entity.r#type = Some(Some(entity_type.into()));
+        self
+    }
+
+    /// Clear the entity type
+    pub fn clear_type(mut self) -> Self {
+        self.entity.r#type = Some(None);
+        self
+    }
+
+    /// Set the description of the business
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.entity.description = Some(description.into());
+        self
+    }
+
+    /// Set the industry
+    pub fn industry(mut self, industry: impl Into<String>) -> Self {
+        self.entity.industry = Some(industry.into());
+        self
+    }
+
+    /// Set the registration number
+    pub fn registration_number(mut self, registration_number: impl Into<String>) -> Self {
+        self.entity.registration_number = Some(registration_number.into());
+        self
+    }
+
+    /// Set the tax ID
+    pub fn tax_id(mut self, tax_id: impl Into<String>) -> Self {
+        self.entity.tax_id = Some(tax_id.into());
+        self
+    }
+
+    /// Set the website
+    pub fn website(mut self, website: impl Into<String>) -> Self {
+        self.entity.website = Some(website.into());
+        self
+    }
+
+    /// Set the expected monthly spend
+    pub fn expected_spend(mut self, expected_spend: impl Into<String>) -> Self {
+        self.entity.expected_spend = Some(Some(expected_spend.into()));
+        self
+    }
+
+    /// Clear the expected monthly spend
+    pub fn clear_expected_spend(mut self) -> Self {
+        self.entity.expected_spend = Some(None);
+        self
+    }
+
+    /// Build the entity update
+    pub fn build(self) -> EntityUpdateInfo {
+        self.entity
+    }
 }
 
 /// Representative information
@@ -172,7 +283,7 @@ pub struct UltimateBeneficialOwnerResponse {
 }
 
 /// Request to update a company application
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateCompanyApplicationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -463,8 +574,140 @@ pub struct UserApplicationResponse {
     pub application_reason: Option<String>,
 }
 
+/// Where to send a user after their application was submitted or initiated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextStep {
+    /// Complete identity verification with the external provider
+    Verify(url::Url),
+    /// Finish filling in the application in the hosted flow
+    Complete(url::Url),
+    /// The application needs nothing more from the user
+    NoneNeeded,
+}
+
+impl NextStep {
+    /// Get the link to send the user to, if the step has one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::applications::NextStep;
+    ///
+    /// let url = url::Url::parse("https://verify.example.com").unwrap();
+    /// assert_eq!(NextStep::Verify(url.clone()).url(), Some(&url));
+    /// assert_eq!(NextStep::NoneNeeded.url(), None);
+    /// ```
+    pub fn url(&self) -> Option<&url::Url> {
+        match self {
+            NextStep::Verify(url) | NextStep::Complete(url) => Some(url),
+            NextStep::NoneNeeded => None,
+        }
+    }
+}
+
+impl UserApplicationResponse {
+    /// Get where to send the user next, based on the links in the response
+    ///
+    /// The external verification link takes precedence over the completion link.
+    /// Returns `None` if the chosen link's URL is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::applications::{NextStep, UserApplicationResponse};
+    ///
+    /// let user_id = "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11";
+    /// let response = |links: &str| -> UserApplicationResponse {
+    ///     serde_json::from_str(&format!(r#"{{"id":"{user_id}"{links}}}"#)).unwrap()
+    /// };
+    /// let verify = format!(
+    ///     r#","applicationExternalVerificationLink":{{"url":"https://verify.example.com","params":{{"userId":"{user_id}"}}}}"#
+    /// );
+    /// let complete = format!(
+    ///     r#","applicationCompletionLink":{{"url":"https://apply.example.com","params":{{"userId":"{user_id}"}}}}"#
+    /// );
+    ///
+    /// let verify_url = url::Url::parse(&format!("https://verify.example.com/?userId={user_id}")).unwrap();
+    /// let complete_url = url::Url::parse(&format!("https://apply.example.com/?userId={user_id}")).unwrap();
+    ///
+    /// assert_eq!(response(&verify).next_step(), Some(NextStep::Verify(verify_url.clone())));
+    /// assert_eq!(response(&complete).next_step(), Some(NextStep::Complete(complete_url)));
+    /// assert_eq!(
+    ///     response(&format!("{verify}{complete}")).next_step(),
+    ///     Some(NextStep::Verify(verify_url))
+    /// );
+    /// assert_eq!(response("").next_step(), Some(NextStep::NoneNeeded));
+    ///
+    /// let broken = format!(
+    ///     r#","applicationCompletionLink":{{"url":"not a url","params":{{"userId":"{user_id}"}}}}"#
+    /// );
+    /// assert_eq!(response(&broken).next_step(), None);
+    /// ```
+    pub fn next_step(&self) -> Option<NextStep> {
+        if let Some(link) = &self.application_external_verification_link {
+            link.to_url().map(NextStep::Verify)
+        } else if let Some(link) = &self.application_completion_link {
+            link.to_url().map(NextStep::Complete)
+        } else {
+            Some(NextStep::NoneNeeded)
+        }
+    }
+
+    /// Get the step the user must take for a `needsInformation` or
+    /// `needsVerification` application
+    ///
+    /// The [`next_step`](Self::next_step) when it carries a link, so the external
+    /// verification link takes precedence over the completion link. Returns `None`
+    /// if neither link is present, or if the chosen link's URL is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::applications::{NextStep, UserApplicationResponse};
+    ///
+    /// let user_id = "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11";
+    /// let response = |links: &str| -> UserApplicationResponse {
+    ///     serde_json::from_str(&format!(r#"{{"id":"{user_id}"{links}}}"#)).unwrap()
+    /// };
+    /// let verify = format!(
+    ///     r#","applicationExternalVerificationLink":{{"url":"https://verify.example.com","params":{{"userId":"{user_id}"}}}}"#
+    /// );
+    /// let complete = format!(
+    ///     r#","applicationCompletionLink":{{"url":"https://apply.example.com","params":{{"userId":"{user_id}"}}}}"#
+    /// );
+    /// let verify_url = url::Url::parse(&format!("https://verify.example.com/?userId={user_id}")).unwrap();
+    /// let complete_url = url::Url::parse(&format!("https://apply.example.com/?userId={user_id}")).unwrap();
+    ///
+    /// // Only verification
+    /// assert_eq!(
+    ///     response(&verify).required_action_link(),
+    ///     Some(NextStep::Verify(verify_url.clone()))
+    /// );
+    ///
+    /// // Only completion
+    /// assert_eq!(
+    ///     response(&complete).required_action_link(),
+    ///     Some(NextStep::Complete(complete_url))
+    /// );
+    ///
+    /// // Both, in either order on the wire
+    /// for links in [format!("{verify}{complete}"), format!("{complete}{verify}")] {
+    ///     assert_eq!(
+    ///         response(&links).required_action_link(),
+    ///         Some(NextStep::Verify(verify_url.clone()))
+    ///     );
+    /// }
+    ///
+    /// // Neither
+    /// assert_eq!(response("").required_action_link(), None);
+    /// ```
+    pub fn required_action_link(&self) -> Option<NextStep> {
+        self.next_step().filter(|step| step.url().is_some())
+    }
+}
+
 /// Request to update a user application
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserApplicationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -507,5 +750,118 @@ pub struct DocumentUploadParams {
     pub country: Option<String>,
     pub country_code: Option<String>,
     pub name: Option<String>, // Only for company documents
-    pub file_path: String,
+    pub source: DocumentSource,
+    /// MIME type of the document, sent as `application/octet-stream` if unset
+    pub mime_type: Option<String>,
+}
+
+/// Where the contents of an uploaded document come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocumentSource {
+    /// Read the document from a file, uploaded under the file's name
+    Path(PathBuf),
+    /// Upload contents held in memory under the given file name
+    Bytes { name: String, bytes: Vec<u8> },
 }
+
+impl DocumentUploadParams {
+    /// Create upload parameters from base64-encoded contents
+    ///
+    /// Accepts raw base64 or a `data:<mime>;base64,<data>` URI, as sent by browsers.
+    /// A data URI's MIME type is kept for the upload and used for the file name's
+    /// extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Raw base64 or a base64 data URI
+    /// * `document_type` - The document type, e.g. `"idCard"`
+    /// * `side` - The document side, e.g. `"front"`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::ValidationError`] if `input` is a data URI that is not
+    /// base64-encoded or if the data is not valid base64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::applications::{DocumentSource, DocumentUploadParams};
+    ///
+    /// let params =
+    ///     DocumentUploadParams::from_base64("data:image/png;base64,aGVsbG8=", "idCard", "front")
+    ///         .unwrap();
+    /// assert_eq!(params.mime_type.as_deref(), Some("image/png"));
+    /// assert_eq!(
+    ///     params.source,
+    ///     DocumentSource::Bytes { name: "document.png".to_string(), bytes: b"hello".to_vec() }
+    /// );
+    ///
+    /// let params = DocumentUploadParams::from_base64("aGVsbG8=", "idCard", "back").unwrap();
+    /// assert_eq!(params.mime_type, None);
+    /// assert_eq!(
+    ///     params.source,
+    ///     DocumentSource::Bytes { name: "document".to_string(), bytes: b"hello".to_vec() }
+    /// );
+    ///
+    /// assert!(DocumentUploadParams::from_base64("not base64!", "idCard", "front").is_err());
+    /// assert!(DocumentUploadParams::from_base64("data:text/plain,hello", "idCard", "front").is_err());
+    /// ```
+    ///
+    /// [`RainError::ValidationError`]: crate::error::RainError::ValidationError
+    pub fn from_base64(
+        input: &str,
+        document_type: impl Into<String>,
+        side: impl Into<String>,
+    ) -> Result<Self, crate::error::RainError> {
+        use crate::error::RainError;
+        use base64::Engine as _;
+
+        let (mime_type, encoded) = match input.trim().strip_prefix("data:") {
+            Some(uri) => {
+                let (header, encoded) = uri.split_once(',').ok_or_else(|| {
+                    RainError::ValidationError("Data URI has no data".to_string())
+                })?;
+                let mime_type = header.strip_suffix(";base64").ok_or_else(|| {
+                    RainError::ValidationError(format!("Data URI is not base64-encoded: {header}"))
+                })?;
+                let mime_type = mime_type.split(';').next().unwrap_or_default();
+                (Some(mime_type).filter(|m| !m.is_empty()), encoded)
+            }
+            None => (None, input.trim()),
+        };
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| RainError::ValidationError(format!("Invalid base64 document: {e}")))?;
+
+        let name = match mime_type.and_then(|m| m.split_once('/')) {
+            Some((_, subtype)) if subtype.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                format!("document.{subtype}")
+            }
+            _ => "document".to_string(),
+        };
+
+        Ok(Self {
+            document_type: document_type.into(),
+            side: side.into(),
+            country: None,
+            country_code: None,
+            name: None,
+            source: DocumentSource::Bytes { name, bytes },
+            mime_type: mime_type.map(str::to_string),
+        })
+    }
+}
+
+impl_with_phone!(
+    InitialUser,
+    Representative,
+    UltimateBeneficialOwner,
+    CreateUserApplicationRequest,
+);
+
+impl_application_reason!(
+    CompanyApplicationResponse,
+    UltimateBeneficialOwnerResponse,
+    UserApplicationResponse,
+);
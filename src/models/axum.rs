@@ -5,6 +5,10 @@
 //!
 //! These types wrap the SDK's request types, allowing them to be used directly
 //! with Axum's JSON extractor while maintaining compatibility with the SDK's internal types.
+//!
+//! The core response models (`Card`, `User`, `Transaction`, `BalanceResponse` and the
+//! types they contain) also derive `utoipa::ToSchema` directly under this feature, so
+//! they can be referenced in OpenAPI documents with their real fields.
 
 #[cfg(feature = "axum")]
 use crate::models::applications::{
@@ -60,12 +64,89 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "axum")]
 use utoipa::ToSchema;
 
+/// Error converting a request wrapper into its SDK request type
+///
+/// Returned by the wrappers' `TryFrom` conversions when the JSON body does not
+/// match the request type. It implements Axum's `IntoResponse` as a
+/// `422 Unprocessable Entity`, so handlers can propagate it with `?`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "axum")]
+/// # {
+/// use axum_core::response::IntoResponse;
+/// use rain_sdk::models::axum::{CreateCardRequestWrapper, WrapperError};
+/// use rain_sdk::models::cards::CreateCardRequest;
+///
+/// let malformed: CreateCardRequestWrapper =
+///     serde_json::from_str(r#"{"type":"plastic"}"#).unwrap();
+/// let err: WrapperError = CreateCardRequest::try_from(malformed).unwrap_err();
+/// assert_eq!(err.target, "CreateCardRequest");
+/// assert_eq!(err.into_response().status(), 422);
+/// # }
+/// ```
+#[cfg(feature = "axum")]
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid {target}: {source}")]
+pub struct WrapperError {
+    /// Name of the request type the body failed to convert into
+    pub target: &'static str,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+#[cfg(feature = "axum")]
+impl WrapperError {
+    /// Deserialize a wrapper's JSON into `T`, naming `T` on failure
+    fn convert<T: serde::de::DeserializeOwned>(
+        inner: serde_json::Value,
+    ) -> Result<T, WrapperError> {
+        serde_json::from_value(inner).map_err(|source| {
+            let full_name = std::any::type_name::<T>();
+            WrapperError {
+                target: full_name.rsplit("::").next().unwrap_or(full_name),
+                source,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for WrapperError {
+    fn into_response(self) -> axum_core::response::Response {
+        (reqwest::StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response()
+    }
+}
+
 // ============================================================================
 // Request Wrapper Types (for Deserialization)
 // ============================================================================
 
 // Applications
 /// Wrapper for InitiateUserApplicationRequest that implements Deserialize
+///
+/// Convert with `TryFrom`; the [`WrapperError`] it returns on bad input
+/// renders as a 422 response.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "axum")]
+/// # {
+/// use rain_sdk::models::applications::InitiateUserApplicationRequest;
+/// use rain_sdk::models::axum::InitiateUserApplicationRequestWrapper;
+///
+/// let wrapper: InitiateUserApplicationRequestWrapper =
+///     serde_json::from_str(r#"{"firstName":"Ada","email":"ada@example.com"}"#).unwrap();
+/// let request = InitiateUserApplicationRequest::try_from(wrapper).unwrap();
+/// assert_eq!(request.first_name.as_deref(), Some("Ada"));
+///
+/// let malformed: InitiateUserApplicationRequestWrapper =
+///     serde_json::from_str(r#"{"firstName":42}"#).unwrap();
+/// assert!(InitiateUserApplicationRequest::try_from(malformed).is_err());
+/// # }
+/// ```
 #[cfg(feature = "axum")]
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct InitiateUserApplicationRequestWrapper {
@@ -75,17 +156,11 @@ pub struct InitiateUserApplicationRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<InitiateUserApplicationRequestWrapper> for InitiateUserApplicationRequest {
-    fn from(wrapper: InitiateUserApplicationRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner).unwrap_or_else(|_| {
-            // Fallback to default if deserialization fails
-            InitiateUserApplicationRequest {
-                first_name: None,
-                last_name: None,
-                email: None,
-                wallet_address: None,
-            }
-        })
+impl TryFrom<InitiateUserApplicationRequestWrapper> for InitiateUserApplicationRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: InitiateUserApplicationRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -96,6 +171,20 @@ impl From<InitiateUserApplicationRequestWrapper> for InitiateUserApplicationRequ
 pub struct CreateUserApplicationRequestWrapper(pub CreateUserApplicationRequest);
 
 /// Wrapper for UpdateUserApplicationRequest that implements Deserialize
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "axum")]
+/// # {
+/// use rain_sdk::models::applications::UpdateUserApplicationRequest;
+/// use rain_sdk::models::axum::UpdateUserApplicationRequestWrapper;
+///
+/// let malformed: UpdateUserApplicationRequestWrapper =
+///     serde_json::from_str(r#"{"isTermsOfServiceAccepted":"yes"}"#).unwrap();
+/// assert!(UpdateUserApplicationRequest::try_from(malformed).is_err());
+/// # }
+/// ```
 #[cfg(feature = "axum")]
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateUserApplicationRequestWrapper {
@@ -105,23 +194,11 @@ pub struct UpdateUserApplicationRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<UpdateUserApplicationRequestWrapper> for UpdateUserApplicationRequest {
-    fn from(wrapper: UpdateUserApplicationRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner).unwrap_or_else(|_| UpdateUserApplicationRequest {
-            first_name: None,
-            last_name: None,
-            birth_date: None,
-            national_id: None,
-            country_of_issue: None,
-            address: None,
-            ip_address: None,
-            occupation: None,
-            annual_salary: None,
-            account_purpose: None,
-            expected_monthly_volume: None,
-            is_terms_of_service_accepted: None,
-            has_existing_documents: None,
-        })
+impl TryFrom<UpdateUserApplicationRequestWrapper> for UpdateUserApplicationRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: UpdateUserApplicationRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -135,10 +212,11 @@ pub struct CreateCompanyApplicationRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<CreateCompanyApplicationRequestWrapper> for CreateCompanyApplicationRequest {
-    fn from(wrapper: CreateCompanyApplicationRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner)
-            .expect("Failed to deserialize CreateCompanyApplicationRequest")
+impl TryFrom<CreateCompanyApplicationRequestWrapper> for CreateCompanyApplicationRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: CreateCompanyApplicationRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -152,10 +230,11 @@ pub struct UpdateCompanyApplicationRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<UpdateCompanyApplicationRequestWrapper> for UpdateCompanyApplicationRequest {
-    fn from(wrapper: UpdateCompanyApplicationRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner)
-            .expect("Failed to deserialize UpdateCompanyApplicationRequest")
+impl TryFrom<UpdateCompanyApplicationRequestWrapper> for UpdateCompanyApplicationRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: UpdateCompanyApplicationRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -170,9 +249,11 @@ pub struct CreateCardRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<CreateCardRequestWrapper> for CreateCardRequest {
-    fn from(wrapper: CreateCardRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner).expect("Failed to deserialize CreateCardRequest")
+impl TryFrom<CreateCardRequestWrapper> for CreateCardRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: CreateCardRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -186,9 +267,11 @@ pub struct UpdateCardRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<UpdateCardRequestWrapper> for UpdateCardRequest {
-    fn from(wrapper: UpdateCardRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner).expect("Failed to deserialize UpdateCardRequest")
+impl TryFrom<UpdateCardRequestWrapper> for UpdateCardRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: UpdateCardRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -216,10 +299,11 @@ pub struct CreateCompanyUserRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<CreateCompanyUserRequestWrapper> for CreateCompanyUserRequest {
-    fn from(wrapper: CreateCompanyUserRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner)
-            .expect("Failed to deserialize CreateCompanyUserRequest")
+impl TryFrom<CreateCompanyUserRequestWrapper> for CreateCompanyUserRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: CreateCompanyUserRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -280,10 +364,11 @@ pub struct CreateShippingGroupRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<CreateShippingGroupRequestWrapper> for CreateShippingGroupRequest {
-    fn from(wrapper: CreateShippingGroupRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner)
-            .expect("Failed to deserialize CreateShippingGroupRequest")
+impl TryFrom<CreateShippingGroupRequestWrapper> for CreateShippingGroupRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: CreateShippingGroupRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
@@ -324,9 +409,11 @@ pub struct UpdateUserRequestWrapper {
 }
 
 #[cfg(feature = "axum")]
-impl From<UpdateUserRequestWrapper> for UpdateUserRequest {
-    fn from(wrapper: UpdateUserRequestWrapper) -> Self {
-        serde_json::from_value(wrapper.inner).expect("Failed to deserialize UpdateUserRequest")
+impl TryFrom<UpdateUserRequestWrapper> for UpdateUserRequest {
+    type Error = WrapperError;
+
+    fn try_from(wrapper: UpdateUserRequestWrapper) -> Result<Self, Self::Error> {
+        WrapperError::convert(wrapper.inner)
     }
 }
 
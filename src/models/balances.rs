@@ -1,14 +1,77 @@
 //! Models for balance endpoints
 
+use crate::models::common::{de_amount, Amount};
 use serde::{Deserialize, Serialize};
 
 /// Balance information response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceResponse {
-    pub credit_limit: i64,
-    pub pending_charges: i64,
-    pub posted_charges: i64,
-    pub balance_due: i64,
-    pub spending_power: i64,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub credit_limit: Amount,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub pending_charges: Amount,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub posted_charges: Amount,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub balance_due: Amount,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub spending_power: Amount,
+    /// Per-currency breakdown, empty when the API sends none
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub balances: Vec<CurrencyBalance>,
+}
+
+impl BalanceResponse {
+    /// Get the balance held in `currency`, compared case-insensitively
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::balances::BalanceResponse;
+    /// use rain_sdk::models::common::Amount;
+    ///
+    /// let json = r#"{
+    ///     "creditLimit": 500000,
+    ///     "pendingCharges": 1200,
+    ///     "postedCharges": 3400,
+    ///     "balanceDue": 4600,
+    ///     "spendingPower": 495400,
+    ///     "balances": [
+    ///         {"currency": "USDC", "amount": 300000},
+    ///         {"currency": "EURC", "amount": "200000"}
+    ///     ]
+    /// }"#;
+    /// let balance: BalanceResponse = serde_json::from_str(json).unwrap();
+    /// assert_eq!(balance.spending_power, Amount::from(495400));
+    /// assert_eq!(balance.balance_for("usdc").unwrap().amount, Amount::from(300000));
+    /// assert_eq!(balance.balance_for("EURC").unwrap().amount, Amount::from(200000));
+    /// assert!(balance.balance_for("USDT").is_none());
+    ///
+    /// let flat = r#"{"creditLimit":0,"pendingCharges":0,"postedCharges":0,"balanceDue":0,"spendingPower":0}"#;
+    /// let balance: BalanceResponse = serde_json::from_str(flat).unwrap();
+    /// assert!(balance.balances.is_empty());
+    /// assert!(!serde_json::to_string(&balance).unwrap().contains("balances"));
+    /// ```
+    pub fn balance_for(&self, currency: &str) -> Option<&CurrencyBalance> {
+        self.balances
+            .iter()
+            .find(|balance| balance.currency.eq_ignore_ascii_case(currency))
+    }
+}
+
+/// Balance held in a single currency or token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
+pub struct CurrencyBalance {
+    pub currency: String,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub amount: Amount,
 }
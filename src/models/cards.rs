@@ -1,10 +1,16 @@
 //! Models for card endpoints
 
+use crate::error::RainError;
+use crate::models::common::{de_amount, de_option_amount, page_limit, Amount};
+use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
 use uuid::Uuid;
 
 /// Card status enum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum CardStatus {
     NotActivated,
@@ -13,16 +19,95 @@ pub enum CardStatus {
     Canceled,
 }
 
+impl CardStatus {
+    /// Wire representation of the status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardStatus::NotActivated => "notActivated",
+            CardStatus::Active => "active",
+            CardStatus::Locked => "locked",
+            CardStatus::Canceled => "canceled",
+        }
+    }
+}
+
 /// Card type enum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum CardType {
     Physical,
     Virtual,
 }
 
+/// Token wallet a card can be provisioned to
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::cards::TokenWallet;
+///
+/// for (wallet, wire) in [
+///     (TokenWallet::ApplePay, "\"applePay\""),
+///     (TokenWallet::GooglePay, "\"googlePay\""),
+///     (TokenWallet::SamsungPay, "\"samsungPay\""),
+/// ] {
+///     assert_eq!(serde_json::from_str::<TokenWallet>(wire).unwrap(), wallet);
+///     assert_eq!(serde_json::to_string(&wallet).unwrap(), wire);
+/// }
+///
+/// let other: TokenWallet = serde_json::from_str("\"clickToPay\"").unwrap();
+/// assert_eq!(other, TokenWallet::Other("clickToPay".to_string()));
+/// assert_eq!(serde_json::to_string(&other).unwrap(), "\"clickToPay\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TokenWallet {
+    ApplePay,
+    GooglePay,
+    SamsungPay,
+    /// A wallet the SDK does not model yet
+    Other(String),
+}
+
+impl TokenWallet {
+    /// Wire representation of the wallet
+    pub fn as_str(&self) -> &str {
+        match self {
+            TokenWallet::ApplePay => "applePay",
+            TokenWallet::GooglePay => "googlePay",
+            TokenWallet::SamsungPay => "samsungPay",
+            TokenWallet::Other(wallet) => wallet,
+        }
+    }
+}
+
+impl From<String> for TokenWallet {
+    fn from(wallet: String) -> Self {
+        match wallet.as_str() {
+            "applePay" => TokenWallet::ApplePay,
+            "googlePay" => TokenWallet::GooglePay,
+            "samsungPay" => TokenWallet::SamsungPay,
+            _ => TokenWallet::Other(wallet),
+        }
+    }
+}
+
+impl From<TokenWallet> for String {
+    fn from(wallet: TokenWallet) -> Self {
+        wallet.as_str().to_string()
+    }
+}
+
+impl fmt::Display for TokenWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Limit frequency enum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum LimitFrequency {
     Per24HourPeriod,
@@ -35,14 +120,39 @@ pub enum LimitFrequency {
 
 /// Card limit
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 pub struct CardLimit {
     pub amount: i64, // Amount in cents
     pub frequency: LimitFrequency,
 }
 
-/// Card configuration
+/// Estimated spending against a card's limit, worked out from the card's transactions
+///
+/// Built by [`RainClient::get_card_spend_summary`](crate::RainClient::get_card_spend_summary),
+/// as the API does not report it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct CardSpendSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<CardLimit>,
+    #[serde(deserialize_with = "de_amount")]
+    pub spent: Amount, // Amount in cents
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "de_option_amount"
+    )]
+    pub remaining: Option<Amount>, // Amount in cents, absent without a limit
+    /// When the oldest counted spend leaves the rolling window and frees up part of
+    /// the limit, absent when nothing counted will leave it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_end: Option<DateTime<Utc>>,
+}
+
+/// Card configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
 pub struct CardConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
@@ -65,6 +175,59 @@ pub enum ShippingMethod {
     UspsInternational,
 }
 
+impl ShippingMethod {
+    /// Rough delivery window in business days, counted from when the card ships
+    ///
+    /// These are typical carrier times for display only; the API does not return
+    /// delivery estimates, and customs can delay international methods further.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::cards::ShippingMethod;
+    ///
+    /// for (method, window) in [
+    ///     (ShippingMethod::Standard, 5..=7),
+    ///     (ShippingMethod::Express, 2..=3),
+    ///     (ShippingMethod::International, 7..=14),
+    ///     (ShippingMethod::Apc, 7..=14),
+    ///     (ShippingMethod::UspsInternational, 10..=20),
+    /// ] {
+    ///     assert_eq!(method.estimated_business_days(), window);
+    /// }
+    /// ```
+    pub fn estimated_business_days(&self) -> RangeInclusive<u32> {
+        match self {
+            ShippingMethod::Standard => 5..=7,
+            ShippingMethod::Express => 2..=3,
+            ShippingMethod::International | ShippingMethod::Apc => 7..=14,
+            ShippingMethod::UspsInternational => 10..=20,
+        }
+    }
+
+    /// Whether the method ships across borders
+    ///
+    /// APC is an international postal carrier, so it counts as international.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::cards::ShippingMethod;
+    ///
+    /// assert!(!ShippingMethod::Standard.is_international());
+    /// assert!(!ShippingMethod::Express.is_international());
+    /// assert!(ShippingMethod::International.is_international());
+    /// assert!(ShippingMethod::Apc.is_international());
+    /// assert!(ShippingMethod::UspsInternational.is_international());
+    /// ```
+    pub fn is_international(&self) -> bool {
+        matches!(
+            self,
+            ShippingMethod::International | ShippingMethod::Apc | ShippingMethod::UspsInternational
+        )
+    }
+}
+
 /// Shipping address for physical cards
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -120,14 +283,38 @@ pub struct CreateCardRequest {
     pub billing: Option<BillingAddress>,
 }
 
+/// Why a card is being replaced
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReissueReason {
+    Lost,
+    Stolen,
+    Damaged,
+}
+
+impl ReissueReason {
+    /// Whether the old card may have been used by someone else
+    ///
+    /// Compromised cards are locked before the replacement is created; a
+    /// damaged card stays usable until its replacement exists.
+    pub fn is_compromised(&self) -> bool {
+        matches!(self, ReissueReason::Lost | ReissueReason::Stolen)
+    }
+}
+
 /// Request to update a card
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateCardRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<CardStatus>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<CardLimit>,
+    /// `None` leaves the limit unchanged, `Some(None)` removes it
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::models::common::double_option"
+    )]
+    pub limit: Option<Option<CardLimit>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing: Option<BillingAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,7 +336,26 @@ pub struct UpdateCardPinRequest {
 }
 
 /// Response for card
+///
+/// With the `axum` feature the card derives `utoipa::ToSchema`, so generated
+/// OpenAPI documents describe its fields.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "axum")]
+/// # {
+/// use rain_sdk::models::cards::Card;
+/// use utoipa::PartialSchema;
+///
+/// let schema = serde_json::to_value(Card::schema()).unwrap();
+/// assert_eq!(schema["properties"]["last4"]["type"], "string");
+/// assert!(schema["properties"]["status"].is_object());
+/// assert!(schema["required"].as_array().unwrap().contains(&"status".into()));
+/// # }
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
     pub id: Uuid,
@@ -165,6 +371,114 @@ pub struct Card {
     pub expiration_year: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_wallets: Option<Vec<String>>,
+    /// Display name, product and art, when the API returns them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<CardConfiguration>,
+}
+
+impl Card {
+    /// Get the card number masked down to its last four digits
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use rain_sdk::models::cards::Card;
+    /// # let card: Card = serde_json::from_str(r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","userId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12","type":"virtual","status":"active","last4":"1234","expirationMonth":"3","expirationYear":"2027"}"#).unwrap();
+    /// assert_eq!(card.masked_number(), "•••• •••• •••• 1234");
+    /// ```
+    pub fn masked_number(&self) -> String {
+        format!("•••• •••• •••• {}", self.last4)
+    }
+
+    /// Get the expiry date formatted as `MM/YY`
+    ///
+    /// Accepts both 2- and 4-digit `expiration_year` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use rain_sdk::models::cards::Card;
+    /// # let mut card: Card = serde_json::from_str(r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","userId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12","type":"virtual","status":"active","last4":"1234","expirationMonth":"3","expirationYear":"2027"}"#).unwrap();
+    /// assert_eq!(card.expiry(), "03/27");
+    ///
+    /// card.expiration_year = "27".to_string();
+    /// assert_eq!(card.expiry(), "03/27");
+    /// ```
+    pub fn expiry(&self) -> String {
+        match self.expiration() {
+            Some((year, month)) => format!("{month:02}/{:02}", year % 100),
+            None => format!("{}/{}", self.expiration_month, self.expiration_year),
+        }
+    }
+
+    /// Check whether the card has expired at the given instant
+    ///
+    /// A card is valid through the last day of its expiration month. Returns
+    /// `false` if the expiration fields cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use rain_sdk::models::cards::Card;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// # let card: Card = serde_json::from_str(r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","userId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12","type":"virtual","status":"active","last4":"1234","expirationMonth":"3","expirationYear":"27"}"#).unwrap();
+    /// assert!(!card.is_expired(Utc.with_ymd_and_hms(2027, 3, 31, 23, 59, 59).unwrap()));
+    /// assert!(card.is_expired(Utc.with_ymd_and_hms(2027, 4, 1, 0, 0, 0).unwrap()));
+    /// ```
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        let Some((year, month)) = self.expiration() else {
+            return false;
+        };
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        match Utc.with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0) {
+            chrono::LocalResult::Single(first_invalid) => now >= first_invalid,
+            _ => false,
+        }
+    }
+
+    /// Get the token wallets the card is provisioned to as typed values
+    ///
+    /// Returns an empty list when the card has no token wallets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use rain_sdk::models::cards::{Card, TokenWallet};
+    /// # let card: Card = serde_json::from_str(r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","userId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12","type":"virtual","status":"active","last4":"1234","expirationMonth":"3","expirationYear":"2027","tokenWallets":["applePay","googlePay"]}"#).unwrap();
+    /// assert_eq!(
+    ///     card.token_wallets_typed(),
+    ///     vec![TokenWallet::ApplePay, TokenWallet::GooglePay]
+    /// );
+    /// ```
+    pub fn token_wallets_typed(&self) -> Vec<TokenWallet> {
+        self.token_wallets
+            .iter()
+            .flatten()
+            .map(|wallet| TokenWallet::from(wallet.clone()))
+            .collect()
+    }
+
+    /// Parse the expiration fields into a four-digit year and a month
+    fn expiration(&self) -> Option<(i32, u32)> {
+        let month: u32 = self.expiration_month.trim().parse().ok()?;
+        let year: i32 = self.expiration_year.trim().parse().ok()?;
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let year = if year < 100 { 2000 + year } else { year };
+        Some((year, month))
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.masked_number(), self.expiry())
+    }
 }
 
 /// Response for card secrets (encrypted PAN and CVC)
@@ -191,8 +505,130 @@ pub struct ProcessorDetails {
     pub time_based_secret: Option<String>,
 }
 
+#[cfg(feature = "crypto")]
+impl ProcessorDetails {
+    /// Compute the current security code from `time_based_secret`
+    ///
+    /// Implements RFC 6238 TOTP with the usual parameters: the secret is base32
+    /// encoded, codes are 6 digits, rotate every 30 seconds and use HMAC-SHA1.
+    /// Returns `None` if there is no secret or it is not valid base32.
+    ///
+    /// Requires the `crypto` feature.
+    ///
+    /// # Examples
+    ///
+    /// Using the RFC 6238 SHA-1 test vectors, truncated to 6 digits:
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use rain_sdk::models::cards::ProcessorDetails;
+    ///
+    /// let details = ProcessorDetails {
+    ///     processor_card_id: "proc_123".to_string(),
+    ///     // base32 of the ASCII secret "12345678901234567890"
+    ///     time_based_secret: Some("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ".to_string()),
+    /// };
+    /// for (timestamp, code) in [
+    ///     (59, "287082"),
+    ///     (1_111_111_109, "081804"),
+    ///     (1_111_111_111, "050471"),
+    ///     (1_234_567_890, "005924"),
+    ///     (2_000_000_000, "279037"),
+    /// ] {
+    ///     let now = Utc.timestamp_opt(timestamp, 0).unwrap();
+    ///     assert_eq!(details.current_totp(now).as_deref(), Some(code));
+    /// }
+    ///
+    /// let missing = ProcessorDetails {
+    ///     time_based_secret: None,
+    ///     ..details.clone()
+    /// };
+    /// assert_eq!(missing.current_totp(Utc::now()), None);
+    ///
+    /// let invalid = ProcessorDetails {
+    ///     time_based_secret: Some("not base32!".to_string()),
+    ///     ..details
+    /// };
+    /// assert_eq!(invalid.current_totp(Utc::now()), None);
+    /// ```
+    pub fn current_totp(&self, now: DateTime<Utc>) -> Option<String> {
+        use hmac::{Hmac, Mac};
+
+        const STEP_SECS: i64 = 30;
+        const DIGITS: u32 = 6;
+
+        let secret = decode_base32(self.time_based_secret.as_deref()?)?;
+        let counter = now.timestamp().div_euclid(STEP_SECS) as u64;
+
+        let mut mac = Hmac::<sha1::Sha1>::new_from_slice(&secret).ok()?;
+        mac.update(&counter.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // Dynamic truncation (RFC 4226 section 5.3)
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes(digest[offset..offset + 4].try_into().ok()?) & 0x7fff_ffff;
+        let code = binary % 10u32.pow(DIGITS);
+        Some(format!("{code:0width$}", width = DIGITS as usize))
+    }
+}
+
+/// Decode RFC 4648 base32, ignoring case, whitespace and padding
+#[cfg(feature = "crypto")]
+fn decode_base32(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u64 = 0;
+    let mut bits = 0;
+    for c in encoded.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u64 - 'A' as u64,
+            c @ '2'..='7' => c as u64 - '2' as u64 + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    (!bytes.is_empty()).then_some(bytes)
+}
+
 /// Query parameters for listing cards
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Several statuses can be combined; cards matching any of them are returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::cards::{CardStatus, ListCardsParams};
+///
+/// let params = ListCardsParams::default().with_statuses([CardStatus::Active, CardStatus::Locked]);
+/// assert_eq!(params.to_query_string().unwrap(), "status=active&status=locked&limit=20");
+///
+/// let params = ListCardsParams {
+///     limit: Some(50),
+///     ..ListCardsParams::default()
+/// }
+/// .with_statuses([CardStatus::NotActivated]);
+/// assert_eq!(params.to_query_string().unwrap(), "status=notActivated&limit=50");
+///
+/// // Page sizes above the API maximum are clamped
+/// let params = ListCardsParams {
+///     limit: Some(100_000),
+///     ..ListCardsParams::default()
+/// };
+/// assert_eq!(params.to_query_string().unwrap(), "limit=100");
+///
+/// // Cursors are percent-encoded
+/// let params = ListCardsParams {
+///     cursor: Some("a/b&c=d".to_string()),
+///     ..ListCardsParams::default()
+/// };
+/// assert_eq!(params.to_query_string().unwrap(), "cursor=a%2Fb%26c%3Dd&limit=20");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListCardsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,12 +636,39 @@ pub struct ListCardsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<CardStatus>,
+    pub status: Option<Vec<CardStatus>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
+impl ListCardsParams {
+    /// Filter by any of the given statuses
+    pub fn with_statuses(mut self, statuses: impl IntoIterator<Item = CardStatus>) -> Self {
+        self.status = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Encode the parameters as a query string, repeating `status` once per value
+    pub fn to_query_string(&self) -> Result<String, RainError> {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(company_id) = self.company_id {
+            query.append_pair("companyId", &company_id.to_string());
+        }
+        if let Some(user_id) = self.user_id {
+            query.append_pair("userId", &user_id.to_string());
+        }
+        for status in self.status.iter().flatten() {
+            query.append_pair("status", status.as_str());
+        }
+        if let Some(ref cursor) = self.cursor {
+            query.append_pair("cursor", cursor);
+        }
+        query.append_pair("limit", &page_limit(self.limit).to_string());
+        Ok(query.finish())
+    }
+}
+
 /// Response for list of cards (just an array of cards)
 pub type ListCardsResponse = Vec<Card>;
@@ -2,14 +2,57 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Request to create a charge
+///
+/// `currency` and `metadata` are left out of the body when `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::charges::CreateChargeRequest;
+/// use serde_json::json;
+/// use std::collections::HashMap;
+///
+/// let mut request = CreateChargeRequest {
+///     amount: 1000,
+///     description: "Custom fee".to_string(),
+///     currency: None,
+///     metadata: None,
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&request).unwrap(),
+///     json!({ "amount": 1000, "description": "Custom fee" })
+/// );
+///
+/// request.currency = Some("EUR".to_string());
+/// request.metadata = Some(HashMap::from([("invoice".to_string(), "INV-42".to_string())]));
+/// assert_eq!(
+///     serde_json::to_value(&request).unwrap(),
+///     json!({
+///         "amount": 1000,
+///         "description": "Custom fee",
+///         "currency": "EUR",
+///         "metadata": { "invoice": "INV-42" }
+///     })
+/// );
+///
+/// let parsed: CreateChargeRequest =
+///     serde_json::from_str(r#"{"amount":1000,"description":"Custom fee"}"#).unwrap();
+/// assert!(parsed.currency.is_none() && parsed.metadata.is_none());
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateChargeRequest {
     pub amount: i64, // Amount in cents, must be >= 1
     pub description: String,
+    /// ISO 4217 currency code; the program's default currency when `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 /// Charge information
@@ -1,10 +1,13 @@
 //! Common types and models for the Rain SDK
 
+use crate::error::RainError;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use uuid::Uuid;
 
 /// Address structure (PhysicalAddress in OpenAPI)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     pub line1: String,
@@ -20,6 +23,7 @@ pub struct Address {
 
 /// Application link with parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationLink {
     pub url: String,
@@ -28,13 +32,142 @@ pub struct ApplicationLink {
 
 /// Application link parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationLinkParams {
     pub user_id: Uuid,
 }
 
+impl ApplicationLink {
+    /// Get the full URL to send the user to, with `params` added as the query
+    ///
+    /// Returns `None` if `url` is not a valid absolute URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::common::{ApplicationLink, ApplicationLinkParams};
+    /// use uuid::Uuid;
+    ///
+    /// let user_id = Uuid::new_v4();
+    /// let link = ApplicationLink {
+    ///     url: "https://verify.example.com/start".to_string(),
+    ///     params: ApplicationLinkParams { user_id },
+    /// };
+    /// assert_eq!(
+    ///     link.to_url().unwrap().as_str(),
+    ///     format!("https://verify.example.com/start?userId={user_id}")
+    /// );
+    /// ```
+    pub fn to_url(&self) -> Option<url::Url> {
+        let mut url = url::Url::parse(&self.url).ok()?;
+        url.query_pairs_mut()
+            .append_pair("userId", &self.params.user_id.to_string());
+        Some(url)
+    }
+}
+
+/// Phone number split into the country code and number sent on the wire
+///
+/// Serializes to the `phoneCountryCode` and `phoneNumber` fields used across
+/// users, applications and shipping.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::common::PhoneNumber;
+/// use rain_sdk::models::users::CreateUserRequest;
+///
+/// let phone = PhoneNumber::new("1", "5555555555").unwrap();
+/// assert_eq!(phone.to_string(), "+1 5555555555");
+///
+/// let json = serde_json::to_value(&phone).unwrap();
+/// assert_eq!(json["phoneCountryCode"], "1");
+/// assert_eq!(json["phoneNumber"], "5555555555");
+///
+/// assert!(PhoneNumber::new("1234", "5555555555").is_err());
+/// assert!(PhoneNumber::new("+1", "5555555555").is_err());
+/// assert!(PhoneNumber::new("1", "1234567890123456").is_err());
+/// assert!(PhoneNumber::new("44", "").is_err());
+///
+/// let request = CreateUserRequest {
+///     first_name: "Ada".to_string(),
+///     last_name: "Lovelace".to_string(),
+///     email: "ada@example.com".to_string(),
+///     wallet_address: None,
+///     solana_address: None,
+///     address: None,
+///     phone_country_code: None,
+///     phone_number: None,
+/// }
+/// .with_phone(phone);
+/// assert_eq!(request.phone_country_code.as_deref(), Some("1"));
+/// assert_eq!(request.phone_number.as_deref(), Some("5555555555"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoneNumber {
+    /// Country calling code without the leading `+` (1-3 digits)
+    #[serde(rename = "phoneCountryCode")]
+    pub country_code: String,
+    /// Subscriber number (1-15 digits)
+    #[serde(rename = "phoneNumber")]
+    pub number: String,
+}
+
+impl PhoneNumber {
+    /// Create a phone number, validating the documented digit lengths
+    pub fn new(
+        country_code: impl Into<String>,
+        number: impl Into<String>,
+    ) -> Result<Self, RainError> {
+        let phone = Self {
+            country_code: country_code.into(),
+            number: number.into(),
+        };
+        phone.validate()?;
+        Ok(phone)
+    }
+
+    /// Check that the country code has 1-3 digits and the number 1-15 digits
+    pub fn validate(&self) -> Result<(), RainError> {
+        check_digits("phone country code", &self.country_code, 3)?;
+        check_digits("phone number", &self.number, 15)
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "+{} {}", self.country_code, self.number)
+    }
+}
+
+fn check_digits(field: &str, value: &str, max: usize) -> Result<(), RainError> {
+    if value.is_empty() || value.len() > max || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(RainError::ValidationError(format!(
+            "{field} must be 1-{max} digits, got {value:?}"
+        )));
+    }
+    Ok(())
+}
+
+/// Implement `with_phone` for requests with `phone_country_code` and `phone_number` fields
+macro_rules! impl_with_phone {
+    ($($request:ty),* $(,)?) => {$(
+        impl $request {
+            /// Set the phone country code and number from a validated [`PhoneNumber`](crate::models::common::PhoneNumber)
+            pub fn with_phone(mut self, phone: crate::models::common::PhoneNumber) -> Self {
+                self.phone_country_code = Some(phone.country_code);
+                self.phone_number = Some(phone.number);
+                self
+            }
+        }
+    )*};
+}
+pub(crate) use impl_with_phone;
+
 /// Person information structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PersonInfo {
     pub id: Uuid,
     pub first_name: String,
@@ -56,6 +189,7 @@ pub struct PersonInfo {
 
 /// Application status enum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum ApplicationStatus {
     Approved,
@@ -68,6 +202,121 @@ pub enum ApplicationStatus {
     Canceled,
 }
 
+/// Why an application is pending or was denied
+///
+/// Parsed from the `applicationReason` field. Rain publishes no list of reason
+/// codes, so the named variants are best-effort: they match only if the server
+/// sends exactly the camelCase code shown on each variant, and anything else,
+/// including free-text messages, is kept as [`ApplicationReason::Other`]. The
+/// enum is non-exhaustive so more codes can get their own variants later. Use
+/// [`ApplicationReason::as_str`] to show the reason when no variant matches.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::common::ApplicationReason;
+///
+/// for (reason, wire) in [
+///     (ApplicationReason::DocumentUnreadable, "\"documentUnreadable\""),
+///     (ApplicationReason::DocumentExpired, "\"documentExpired\""),
+///     (ApplicationReason::NameMismatch, "\"nameMismatch\""),
+///     (ApplicationReason::AddressMismatch, "\"addressMismatch\""),
+///     (ApplicationReason::SanctionsHit, "\"sanctionsHit\""),
+/// ] {
+///     assert_eq!(serde_json::from_str::<ApplicationReason>(wire).unwrap(), reason);
+///     assert_eq!(serde_json::to_string(&reason).unwrap(), wire);
+/// }
+///
+/// let reason: ApplicationReason = serde_json::from_str("\"Selfie does not match\"").unwrap();
+/// assert_eq!(reason, ApplicationReason::Other("Selfie does not match".to_string()));
+/// assert_eq!(reason.as_str(), "Selfie does not match");
+/// assert_eq!(reason.to_string(), "Selfie does not match");
+/// assert_eq!(serde_json::to_string(&reason).unwrap(), "\"Selfie does not match\"");
+///
+/// // Responses with an `applicationReason` expose it typed
+/// use rain_sdk::models::applications::UltimateBeneficialOwnerResponse;
+///
+/// let owner: UltimateBeneficialOwnerResponse = serde_json::from_str(
+///     r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","applicationReason":"addressMismatch"}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     owner.application_reason_typed(),
+///     Some(ApplicationReason::AddressMismatch)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum ApplicationReason {
+    /// An identity document could not be read (`documentUnreadable`, best-effort)
+    DocumentUnreadable,
+    /// An identity document has expired (`documentExpired`, best-effort)
+    DocumentExpired,
+    /// The name does not match the documents (`nameMismatch`, best-effort)
+    NameMismatch,
+    /// The address could not be verified (`addressMismatch`, best-effort)
+    AddressMismatch,
+    /// The applicant matched a sanctions list (`sanctionsHit`, best-effort)
+    SanctionsHit,
+    /// Any other reason, including free-text messages, as sent by the server
+    Other(String),
+}
+
+impl ApplicationReason {
+    /// Wire representation of the reason
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApplicationReason::DocumentUnreadable => "documentUnreadable",
+            ApplicationReason::DocumentExpired => "documentExpired",
+            ApplicationReason::NameMismatch => "nameMismatch",
+            ApplicationReason::AddressMismatch => "addressMismatch",
+            ApplicationReason::SanctionsHit => "sanctionsHit",
+            ApplicationReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<String> for ApplicationReason {
+    fn from(reason: String) -> Self {
+        match reason.as_str() {
+            "documentUnreadable" => ApplicationReason::DocumentUnreadable,
+            "documentExpired" => ApplicationReason::DocumentExpired,
+            "nameMismatch" => ApplicationReason::NameMismatch,
+            "addressMismatch" => ApplicationReason::AddressMismatch,
+            "sanctionsHit" => ApplicationReason::SanctionsHit,
+            _ => ApplicationReason::Other(reason),
+        }
+    }
+}
+
+impl From<ApplicationReason> for String {
+    fn from(reason: ApplicationReason) -> Self {
+        reason.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ApplicationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Add an `application_reason_typed` accessor to types with an `application_reason` field
+macro_rules! impl_application_reason {
+    ($($response:ty),* $(,)?) => {$(
+        impl $response {
+            /// Get the application reason as a typed [`ApplicationReason`](crate::models::common::ApplicationReason)
+            pub fn application_reason_typed(&self) -> Option<crate::models::common::ApplicationReason> {
+                self.application_reason
+                    .clone()
+                    .map(crate::models::common::ApplicationReason::from)
+            }
+        }
+    )*};
+}
+pub(crate) use impl_application_reason;
+
 /// Document type for company documents
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -118,3 +367,321 @@ pub enum DocumentSide {
     Front,
     Back,
 }
+
+/// Monetary amount in minor units (cents)
+///
+/// This is `i64` by default. Enabling the `decimal` feature switches it to
+/// `rust_decimal::Decimal`, which is exact and cannot overflow on very large
+/// balances. Decimal amounts serialize as strings to preserve precision.
+#[cfg(not(feature = "decimal"))]
+pub type Amount = i64;
+
+/// Monetary amount in minor units (cents)
+///
+/// The `decimal` feature is enabled, so this is an exact [`rust_decimal::Decimal`].
+/// Decimal amounts serialize as strings to preserve precision.
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+/// Quantity of tokens, such as a collateral deposit
+///
+/// Token quantities are not in minor units and may be fractional, so this is
+/// `f64` by default. The `decimal` feature switches it to
+/// `rust_decimal::Decimal`, the same type as [`Amount`], making it exact.
+#[cfg(not(feature = "decimal"))]
+pub type TokenAmount = f64;
+
+/// Quantity of tokens, such as a collateral deposit
+///
+/// The `decimal` feature is enabled, so this is an exact [`rust_decimal::Decimal`].
+/// Decimal amounts serialize as strings to preserve precision.
+#[cfg(feature = "decimal")]
+pub type TokenAmount = rust_decimal::Decimal;
+
+/// Deserialize an amount that may be sent as a JSON number or a numeric string
+///
+/// Some Rain endpoints return amounts as integers while others return them as
+/// strings. Use with `#[serde(deserialize_with = "de_amount")]` on [`Amount`] fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::balances::BalanceResponse;
+///
+/// let from_number: BalanceResponse = serde_json::from_str(
+///     r#"{"creditLimit":1000,"pendingCharges":0,"postedCharges":0,"balanceDue":0,"spendingPower":1000}"#,
+/// ).unwrap();
+/// let from_string: BalanceResponse = serde_json::from_str(
+///     r#"{"creditLimit":"1000","pendingCharges":"0","postedCharges":"0","balanceDue":"0","spendingPower":"1000"}"#,
+/// ).unwrap();
+/// assert_eq!(from_number.credit_limit.to_string(), "1000");
+/// assert_eq!(from_string.credit_limit.to_string(), "1000");
+/// ```
+///
+/// Values beyond the `i64` range are rejected by default and accepted exactly
+/// with the `decimal` feature:
+///
+/// ```rust
+/// use rain_sdk::models::balances::BalanceResponse;
+///
+/// let json = r#"{"creditLimit":"99999999999999999999","pendingCharges":0,"postedCharges":0,"balanceDue":0,"spendingPower":0}"#;
+/// let result = serde_json::from_str::<BalanceResponse>(json);
+///
+/// #[cfg(not(feature = "decimal"))]
+/// assert!(result.is_err());
+/// #[cfg(feature = "decimal")]
+/// assert_eq!(result.unwrap().credit_limit.to_string(), "99999999999999999999");
+/// ```
+pub fn de_amount<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_any(AmountVisitor)
+}
+
+/// Deserialize an optional amount that may be sent as a JSON number, a numeric string, or null
+///
+/// Use with `#[serde(default, deserialize_with = "de_option_amount")]` on `Option<Amount>` fields.
+pub fn de_option_amount<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "de_amount")] Amount);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(amount)| amount))
+}
+
+/// Deserialize a token quantity that may be sent as a JSON number or a numeric string
+///
+/// Use with `#[serde(deserialize_with = "de_token_amount")]` on [`TokenAmount`] fields.
+pub fn de_token_amount<'de, D>(deserializer: D) -> Result<TokenAmount, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[cfg(not(feature = "decimal"))]
+    {
+        deserializer.deserialize_any(TokenAmountVisitor)
+    }
+    #[cfg(feature = "decimal")]
+    {
+        de_amount(deserializer)
+    }
+}
+
+struct AmountVisitor;
+
+#[cfg(not(feature = "decimal"))]
+impl serde::de::Visitor<'_> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "an integer amount or a numeric string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Amount, E> {
+        Ok(value)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Amount, E> {
+        i64::try_from(value).map_err(|_| E::custom(format!("amount {value} is out of range")))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Amount, E> {
+        value
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| E::custom(format!("invalid amount string: {value:?}")))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl serde::de::Visitor<'_> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "a numeric amount or a numeric string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Amount, E> {
+        Ok(Amount::from(value))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Amount, E> {
+        Ok(Amount::from(value))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Amount, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Amount, E> {
+        let value = value.trim();
+        value
+            .parse::<Amount>()
+            .or_else(|_| Amount::from_scientific(value))
+            .map_err(|_| E::custom(format!("invalid amount string: {value:?}")))
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+struct TokenAmountVisitor;
+
+#[cfg(not(feature = "decimal"))]
+impl serde::de::Visitor<'_> for TokenAmountVisitor {
+    type Value = TokenAmount;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "a numeric amount or a numeric string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<TokenAmount, E> {
+        Ok(value as TokenAmount)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<TokenAmount, E> {
+        Ok(value as TokenAmount)
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<TokenAmount, E> {
+        Ok(value)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<TokenAmount, E> {
+        value
+            .trim()
+            .parse::<TokenAmount>()
+            .map_err(|_| E::custom(format!("invalid amount string: {value:?}")))
+    }
+}
+
+/// Largest page size the list endpoints accept
+///
+/// A larger `limit` on any list parameters is lowered to this value before the
+/// request is sent.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Page size requested when list parameters leave `limit` unset
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// Clamp a requested page size to [`MAX_PAGE_SIZE`]
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::common::{clamp_page_limit, MAX_PAGE_SIZE};
+///
+/// assert_eq!(clamp_page_limit(20), 20);
+/// assert_eq!(clamp_page_limit(100_000), MAX_PAGE_SIZE);
+/// ```
+pub fn clamp_page_limit(limit: u32) -> u32 {
+    if limit > MAX_PAGE_SIZE {
+        log::debug!("page limit {limit} is over the maximum, requesting {MAX_PAGE_SIZE}");
+        MAX_PAGE_SIZE
+    } else {
+        limit
+    }
+}
+
+/// The page size to request for an optional `limit`
+///
+/// Clamps a set limit to [`MAX_PAGE_SIZE`] and falls back to
+/// [`DEFAULT_PAGE_SIZE`] when it is unset.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::common::{page_limit, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+///
+/// assert_eq!(page_limit(Some(5)), 5);
+/// assert_eq!(page_limit(Some(100_000)), MAX_PAGE_SIZE);
+/// assert_eq!(page_limit(None), DEFAULT_PAGE_SIZE);
+/// ```
+pub fn page_limit(limit: Option<u32>) -> u32 {
+    match limit {
+        Some(limit) => clamp_page_limit(limit),
+        None => {
+            log::debug!("no page limit set, requesting {DEFAULT_PAGE_SIZE}");
+            DEFAULT_PAGE_SIZE
+        }
+    }
+}
+
+/// Serialize an optional page size with [`page_limit`]
+///
+/// Use with `#[serde(serialize_with = "ser_page_limit")]` on the `limit` field of
+/// list parameters, without `skip_serializing_if`, so an unset limit is sent as
+/// [`DEFAULT_PAGE_SIZE`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::encode_query;
+/// use rain_sdk::models::transactions::ListTransactionsParams;
+///
+/// let params = ListTransactionsParams {
+///     limit: Some(100_000),
+///     ..Default::default()
+/// };
+/// assert_eq!(encode_query(&params).unwrap(), "limit=100");
+/// assert_eq!(
+///     encode_query(&ListTransactionsParams::default()).unwrap(),
+///     "limit=20"
+/// );
+/// ```
+pub fn ser_page_limit<S>(limit: &Option<u32>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    page_limit(*limit).serialize(serializer)
+}
+
+/// Serde adapter distinguishing an absent field from an explicit `null`
+///
+/// Use on `Option<Option<T>>` fields of update requests together with
+/// `#[serde(default, skip_serializing_if = "Option::is_none", with = "double_option")]`:
+///
+/// - `None` - the field is omitted, leaving the value unchanged
+/// - `Some(None)` - the field is sent as `null`, clearing the value
+/// - `Some(Some(value))` - the field is sent with the new value
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::users::UpdateUserRequest;
+///
+/// let mut request: UpdateUserRequest = serde_json::from_str("{}").unwrap();
+/// assert_eq!(request.email, None);
+/// assert_eq!(serde_json::to_string(&request).unwrap(), "{}");
+///
+/// request.email = Some(None);
+/// assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"email":null}"#);
+///
+/// request.email = Some(Some("jane@example.com".to_string()));
+/// assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"email":"jane@example.com"}"#);
+///
+/// let cleared: UpdateUserRequest = serde_json::from_str(r#"{"email":null}"#).unwrap();
+/// assert_eq!(cleared.email, Some(None));
+/// ```
+pub mod double_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(inner) => inner.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Some)
+    }
+}
@@ -40,9 +40,11 @@ pub struct UpdateCompanyRequest {
 pub struct ListCompaniesParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
 /// Response for list of companies (just an array of companies)
 pub type ListCompaniesResponse = Vec<Company>;
+
+impl_application_reason!(Company);
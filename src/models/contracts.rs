@@ -86,6 +86,48 @@ pub struct Contract {
     pub onramp: Option<Onramp>,
 }
 
+impl Contract {
+    /// Get the id of the chain the contract is deployed on
+    pub fn chain_id(&self) -> i64 {
+        self.chain_id
+    }
+}
+
+/// Find the contract deployed on `chain_id`
+///
+/// Companies and users have one contract per chain, so there is at most one match.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::contracts::{contracts_for_chain, Contract};
+///
+/// let contract = |chain_id: i64| -> Contract {
+///     serde_json::from_value(serde_json::json!({
+///         "id": uuid::Uuid::new_v4(),
+///         "chainId": chain_id,
+///         "controllerAddress": "0x3333333333333333333333333333333333333333",
+///         "proxyAddress": "0x4444444444444444444444444444444444444444",
+///         "depositAddress": "0x5555555555555555555555555555555555555555",
+///         "tokens": [],
+///         "contractVersion": 2
+///     }))
+///     .unwrap()
+/// };
+/// let contracts = vec![contract(1), contract(137), contract(8453)];
+///
+/// let polygon = contracts_for_chain(&contracts, 137).unwrap();
+/// assert_eq!(polygon.chain_id(), 137);
+/// assert_eq!(polygon.id, contracts[1].id);
+/// assert!(contracts_for_chain(&contracts, 10).is_none());
+/// assert!(contracts_for_chain(&[], 1).is_none());
+/// ```
+pub fn contracts_for_chain(contracts: &[Contract], chain_id: i64) -> Option<&Contract> {
+    contracts
+        .iter()
+        .find(|contract| contract.chain_id() == chain_id)
+}
+
 /// Request to create a contract for a company
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use uuid::Uuid;
 
 /// Status of a dispute
@@ -15,6 +16,165 @@ pub enum DisputeStatus {
     Canceled,
 }
 
+/// Reason a transaction is being disputed
+///
+/// # Examples
+///
+/// ```
+/// use rain_sdk::models::disputes::DisputeReason;
+///
+/// for (reason, wire) in [
+///     (DisputeReason::Fraud, "\"fraud\""),
+///     (DisputeReason::DuplicateCharge, "\"duplicateCharge\""),
+///     (DisputeReason::ProductNotReceived, "\"productNotReceived\""),
+///     (DisputeReason::ProductUnacceptable, "\"productUnacceptable\""),
+///     (DisputeReason::IncorrectAmount, "\"incorrectAmount\""),
+///     (DisputeReason::SubscriptionCanceled, "\"subscriptionCanceled\""),
+///     (DisputeReason::CreditNotProcessed, "\"creditNotProcessed\""),
+///     (DisputeReason::Other("other".to_string()), "\"other\""),
+/// ] {
+///     assert_eq!(serde_json::to_string(&reason).unwrap(), wire);
+///     assert_eq!(serde_json::from_str::<DisputeReason>(wire).unwrap(), reason);
+/// }
+///
+/// let other: DisputeReason = serde_json::from_str("\"merchantClosed\"").unwrap();
+/// assert_eq!(other, DisputeReason::Other("merchantClosed".to_string()));
+/// assert_eq!(serde_json::to_string(&other).unwrap(), "\"merchantClosed\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum DisputeReason {
+    Fraud,
+    DuplicateCharge,
+    ProductNotReceived,
+    ProductUnacceptable,
+    IncorrectAmount,
+    SubscriptionCanceled,
+    CreditNotProcessed,
+    /// Any other reason, including the API's own `other`, as sent on the wire
+    Other(String),
+}
+
+impl DisputeReason {
+    /// Wire representation of the reason
+    pub fn as_str(&self) -> &str {
+        match self {
+            DisputeReason::Fraud => "fraud",
+            DisputeReason::DuplicateCharge => "duplicateCharge",
+            DisputeReason::ProductNotReceived => "productNotReceived",
+            DisputeReason::ProductUnacceptable => "productUnacceptable",
+            DisputeReason::IncorrectAmount => "incorrectAmount",
+            DisputeReason::SubscriptionCanceled => "subscriptionCanceled",
+            DisputeReason::CreditNotProcessed => "creditNotProcessed",
+            DisputeReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<String> for DisputeReason {
+    fn from(reason: String) -> Self {
+        match reason.as_str() {
+            "fraud" => DisputeReason::Fraud,
+            "duplicateCharge" => DisputeReason::DuplicateCharge,
+            "productNotReceived" => DisputeReason::ProductNotReceived,
+            "productUnacceptable" => DisputeReason::ProductUnacceptable,
+            "incorrectAmount" => DisputeReason::IncorrectAmount,
+            "subscriptionCanceled" => DisputeReason::SubscriptionCanceled,
+            "creditNotProcessed" => DisputeReason::CreditNotProcessed,
+            _ => DisputeReason::Other(reason),
+        }
+    }
+}
+
+impl From<DisputeReason> for String {
+    fn from(reason: DisputeReason) -> Self {
+        reason.as_str().to_string()
+    }
+}
+
+impl fmt::Display for DisputeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Type of evidence attached to a dispute
+///
+/// # Examples
+///
+/// ```
+/// use rain_sdk::models::disputes::EvidenceType;
+///
+/// for (evidence_type, wire) in [
+///     (EvidenceType::Receipt, "receipt"),
+///     (EvidenceType::Invoice, "invoice"),
+///     (EvidenceType::Correspondence, "correspondence"),
+///     (EvidenceType::ProofOfDelivery, "proofOfDelivery"),
+///     (EvidenceType::ProofOfCancellation, "proofOfCancellation"),
+///     (EvidenceType::BankStatement, "bankStatement"),
+///     (EvidenceType::Other("other".to_string()), "other"),
+///     (EvidenceType::Other("screenshot".to_string()), "screenshot"),
+/// ] {
+///     let json = format!("\"{wire}\"");
+///     assert_eq!(serde_json::to_string(&evidence_type).unwrap(), json);
+///     assert_eq!(serde_json::from_str::<EvidenceType>(&json).unwrap(), evidence_type);
+///     assert_eq!(evidence_type.as_str(), wire);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EvidenceType {
+    Receipt,
+    Invoice,
+    Correspondence,
+    ProofOfDelivery,
+    ProofOfCancellation,
+    BankStatement,
+    /// Any other type, including the API's own `other`, as sent on the wire
+    Other(String),
+}
+
+impl EvidenceType {
+    /// Wire representation, as sent in the multipart `type` field
+    pub fn as_str(&self) -> &str {
+        match self {
+            EvidenceType::Receipt => "receipt",
+            EvidenceType::Invoice => "invoice",
+            EvidenceType::Correspondence => "correspondence",
+            EvidenceType::ProofOfDelivery => "proofOfDelivery",
+            EvidenceType::ProofOfCancellation => "proofOfCancellation",
+            EvidenceType::BankStatement => "bankStatement",
+            EvidenceType::Other(evidence_type) => evidence_type,
+        }
+    }
+}
+
+impl From<String> for EvidenceType {
+    fn from(evidence_type: String) -> Self {
+        match evidence_type.as_str() {
+            "receipt" => EvidenceType::Receipt,
+            "invoice" => EvidenceType::Invoice,
+            "correspondence" => EvidenceType::Correspondence,
+            "proofOfDelivery" => EvidenceType::ProofOfDelivery,
+            "proofOfCancellation" => EvidenceType::ProofOfCancellation,
+            "bankStatement" => EvidenceType::BankStatement,
+            _ => EvidenceType::Other(evidence_type),
+        }
+    }
+}
+
+impl From<EvidenceType> for String {
+    fn from(evidence_type: EvidenceType) -> Self {
+        evidence_type.as_str().to_string()
+    }
+}
+
+impl fmt::Display for EvidenceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Dispute information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,8 +189,61 @@ pub struct Dispute {
     pub resolved_at: Option<DateTime<Utc>>,
 }
 
+impl Dispute {
+    /// Check whether the dispute can still be updated or given evidence
+    ///
+    /// Pending and in-review disputes are editable; accepted, rejected and
+    /// canceled ones are final.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::disputes::{Dispute, DisputeStatus};
+    ///
+    /// let dispute = |status: &str| -> Dispute {
+    ///     serde_json::from_str(&format!(
+    ///         r#"{{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11",
+    ///             "transactionId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12",
+    ///             "status":"{status}","createdAt":"2024-01-01T00:00:00Z"}}"#
+    ///     ))
+    ///     .unwrap()
+    /// };
+    ///
+    /// assert!(dispute("pending").is_editable());
+    /// assert!(dispute("inReview").is_editable());
+    /// assert!(!dispute("accepted").is_editable());
+    /// assert!(!dispute("rejected").is_editable());
+    /// assert!(!dispute("canceled").is_editable());
+    /// ```
+    pub fn is_editable(&self) -> bool {
+        matches!(
+            self.status,
+            DisputeStatus::Pending | DisputeStatus::InReview
+        )
+    }
+}
+
 /// Query parameters for listing disputes
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::client::encode_query;
+/// use rain_sdk::models::disputes::ListDisputesParams;
+/// use uuid::Uuid;
+///
+/// let user_id = Uuid::parse_str("6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11").unwrap();
+/// let params = ListDisputesParams {
+///     user_id: Some(user_id),
+///     limit: Some(20),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     encode_query(&params).unwrap(),
+///     "userId=6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11&limit=20"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListDisputesParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,7 +254,7 @@ pub struct ListDisputesParams {
     pub transaction_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
@@ -56,9 +269,25 @@ pub struct UpdateDisputeRequest {
 }
 
 /// Request to create a dispute
+///
+/// # Examples
+///
+/// ```
+/// use rain_sdk::models::disputes::{CreateDisputeRequest, DisputeReason};
+///
+/// let request = CreateDisputeRequest {
+///     reason: Some(DisputeReason::DuplicateCharge),
+///     text_evidence: Some("Charged twice".to_string()),
+/// };
+/// let json = serde_json::to_value(&request).unwrap();
+/// assert_eq!(json["reason"], "duplicateCharge");
+/// assert_eq!(json["textEvidence"], "Charged twice");
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateDisputeRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<DisputeReason>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text_evidence: Option<String>,
 }
@@ -67,7 +296,7 @@ pub struct CreateDisputeRequest {
 #[derive(Debug, Clone)]
 pub struct UploadDisputeEvidenceRequest {
     pub name: String,
-    pub evidence_type: String,
+    pub evidence_type: EvidenceType,
     pub file: Vec<u8>,
 }
 
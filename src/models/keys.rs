@@ -1,5 +1,6 @@
 //! Models for key endpoints
 
+use crate::config::Environment;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -21,3 +22,17 @@ pub struct Key {
     pub name: String,
     pub expires_at: DateTime<Utc>,
 }
+
+/// Identity behind the current API key, as returned by
+/// [`RainClient::whoami`](crate::RainClient::whoami)
+///
+/// The API does not describe API keys, so only the environment, derived from the
+/// configured base URL, is known. The other fields stay `None` until the API
+/// exposes them.
+#[derive(Debug, Clone)]
+pub struct TenantIdentity {
+    pub tenant_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub environment: Environment,
+    pub scopes: Option<Vec<String>>,
+}
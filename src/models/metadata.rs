@@ -0,0 +1,21 @@
+//! Models for reference data such as countries and currencies
+
+use serde::{Deserialize, Serialize};
+
+/// Country supported for applications and shipping
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code, as used in `country_code` fields
+    pub code: String,
+    pub name: String,
+}
+
+/// Currency supported for cards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Currency {
+    /// ISO 4217 code
+    pub code: String,
+    pub name: String,
+}
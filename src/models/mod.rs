@@ -11,6 +11,7 @@ pub mod companies;
 pub mod contracts;
 pub mod disputes;
 pub mod keys;
+pub mod metadata;
 pub mod payments;
 pub mod reports;
 pub mod shipping_groups;
@@ -31,6 +32,7 @@ pub use companies::*;
 pub use contracts::*;
 pub use disputes::*;
 pub use keys::*;
+pub use metadata::*;
 pub use payments::*;
 pub use reports::*;
 pub use shipping_groups::*;
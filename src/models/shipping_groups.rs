@@ -1,9 +1,19 @@
 //! Models for shipping group endpoints
 
-use crate::models::common::Address;
+use crate::models::common::{Address, PhoneNumber};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Fulfillment status of a shipping group
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ShippingGroupStatus {
+    Pending,
+    Shipped,
+    Delivered,
+    Failed,
+}
+
 /// Shipping group information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -17,6 +27,51 @@ pub struct ShippingGroup {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recipient_phone_number: Option<String>,
     pub address: Address,
+    /// Fulfillment status, when the API reports one
+    ///
+    /// Not part of Rain's published shipping group schema; read only if the
+    /// response carries it and `None` otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ShippingGroupStatus>,
+    /// Carrier tracking number, when the API reports one
+    ///
+    /// Optional extra like [`status`](Self::status).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+    /// Shipping carrier, when the API reports one
+    ///
+    /// Optional extra like [`status`](Self::status).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+}
+
+impl ShippingGroup {
+    /// Check whether the shipping group has left the warehouse
+    ///
+    /// Returns `true` for [`ShippingGroupStatus::Shipped`] and [`ShippingGroupStatus::Delivered`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::shipping_groups::{ShippingGroup, ShippingGroupStatus};
+    ///
+    /// let group: ShippingGroup = serde_json::from_str(r#"{
+    ///     "id": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11",
+    ///     "recipientFirstName": "Jane",
+    ///     "address": {"line1": "1 Main St", "city": "New York", "region": "NY", "postalCode": "10001", "countryCode": "US"},
+    ///     "status": "shipped",
+    ///     "trackingNumber": "1Z999",
+    ///     "carrier": "UPS"
+    /// }"#).unwrap();
+    /// assert_eq!(group.status, Some(ShippingGroupStatus::Shipped));
+    /// assert!(group.is_shipped());
+    /// ```
+    pub fn is_shipped(&self) -> bool {
+        matches!(
+            self.status,
+            Some(ShippingGroupStatus::Shipped | ShippingGroupStatus::Delivered)
+        )
+    }
 }
 
 /// Request to create a shipping group
@@ -33,13 +88,22 @@ pub struct CreateShippingGroupRequest {
     pub address: Address,
 }
 
+impl CreateShippingGroupRequest {
+    /// Set the recipient phone country code and number from a validated [`PhoneNumber`]
+    pub fn with_recipient_phone(mut self, phone: PhoneNumber) -> Self {
+        self.recipient_phone_country_code = Some(phone.country_code);
+        self.recipient_phone_number = Some(phone.number);
+        self
+    }
+}
+
 /// Query parameters for listing shipping groups
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListShippingGroupsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
@@ -1,5 +1,6 @@
 //! Models for signature endpoints
 
+use crate::error::{RainError, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -86,3 +87,232 @@ pub struct WithdrawalSignatureParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rain_collateral_contract_id: Option<Uuid>,
 }
+
+impl WithdrawalSignatureParams {
+    /// Create a builder for withdrawal signature parameters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::signatures::WithdrawalSignatureParams;
+    ///
+    /// let params = WithdrawalSignatureParams::builder()
+    ///     .chain_id(1)
+    ///     .token("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")
+    ///     .amount("1000000")
+    ///     .admin_address("0x1111111111111111111111111111111111111111")
+    ///     .recipient_address("0x2222222222222222222222222222222222222222")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.amount, "1000000");
+    ///
+    /// // A malformed recipient is rejected before any request is made
+    /// let err = WithdrawalSignatureParams::builder()
+    ///     .token("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")
+    ///     .amount("1000000")
+    ///     .admin_address("0x1111111111111111111111111111111111111111")
+    ///     .recipient_address("not-an-address")
+    ///     .build();
+    /// assert!(err.is_err());
+    /// ```
+    pub fn builder() -> WithdrawalSignatureParamsBuilder {
+        WithdrawalSignatureParamsBuilder::default()
+    }
+
+    /// Validate the parameters before requesting a signature
+    ///
+    /// Checks that `token`, `admin_address`, and `recipient_address` are
+    /// well-formed for the chain and that `amount` is a positive integer in the
+    /// token's base units.
+    ///
+    /// Addresses on a known EVM chain must be `0x` followed by 40 hex characters,
+    /// and addresses on Tron must be base58 starting with `T`. Without a
+    /// `chain_id` the API picks the chain, so any EVM, Solana, Tron or Stellar
+    /// address is accepted. Addresses on other chains are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::ValidationError`] describing the first invalid field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::signatures::WithdrawalSignatureParams;
+    ///
+    /// let mut params = WithdrawalSignatureParams::builder()
+    ///     .token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+    ///     .amount("1000000")
+    ///     .admin_address("4Nd1mYQmZ7qKq1Z5Zr5bK8xH7nYfJ1bCk9v2o3p4q5r6")
+    ///     .recipient_address("GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // A Stellar recipient cannot receive on Ethereum
+    /// params.chain_id = Some(1);
+    /// assert!(params.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        validate_address("token", &self.token, self.chain_id)?;
+        validate_address("adminAddress", &self.admin_address, self.chain_id)?;
+        validate_address("recipientAddress", &self.recipient_address, self.chain_id)?;
+
+        match self.amount.parse::<u128>() {
+            Ok(amount) if amount > 0 => Ok(()),
+            _ => Err(RainError::ValidationError(format!(
+                "amount must be a positive integer, got {:?}",
+                self.amount
+            ))),
+        }
+    }
+}
+
+/// Builder for [`WithdrawalSignatureParams`]
+#[derive(Debug, Clone, Default)]
+pub struct WithdrawalSignatureParamsBuilder {
+    chain_id: Option<i64>,
+    token: Option<String>,
+    amount: Option<String>,
+    admin_address: Option<String>,
+    recipient_address: Option<String>,
+    is_amount_native: Option<bool>,
+    rain_collateral_contract_id: Option<Uuid>,
+}
+
+impl WithdrawalSignatureParamsBuilder {
+    /// Set the chain ID
+    pub fn chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Set the token address
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Set the amount in the token's base units
+    pub fn amount(mut self, amount: impl Into<String>) -> Self {
+        self.amount = Some(amount.into());
+        self
+    }
+
+    /// Set the admin address that controls the collateral contract
+    pub fn admin_address(mut self, admin_address: impl Into<String>) -> Self {
+        self.admin_address = Some(admin_address.into());
+        self
+    }
+
+    /// Set the address that will receive the withdrawn funds
+    pub fn recipient_address(mut self, recipient_address: impl Into<String>) -> Self {
+        self.recipient_address = Some(recipient_address.into());
+        self
+    }
+
+    /// Set whether the amount is denominated in the chain's native token
+    pub fn is_amount_native(mut self, is_amount_native: bool) -> Self {
+        self.is_amount_native = Some(is_amount_native);
+        self
+    }
+
+    /// Set the Rain collateral contract ID
+    pub fn rain_collateral_contract_id(mut self, contract_id: Uuid) -> Self {
+        self.rain_collateral_contract_id = Some(contract_id);
+        self
+    }
+
+    /// Build and validate the parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::ValidationError`] if a required field is missing or
+    /// [`WithdrawalSignatureParams::validate`] fails.
+    pub fn build(self) -> Result<WithdrawalSignatureParams> {
+        let params = WithdrawalSignatureParams {
+            chain_id: self.chain_id,
+            token: required("token", self.token)?,
+            amount: required("amount", self.amount)?,
+            admin_address: required("adminAddress", self.admin_address)?,
+            recipient_address: required("recipientAddress", self.recipient_address)?,
+            is_amount_native: self.is_amount_native,
+            rain_collateral_contract_id: self.rain_collateral_contract_id,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+}
+
+fn required(field: &str, value: Option<String>) -> Result<String> {
+    value.ok_or_else(|| RainError::ValidationError(format!("{field} is required")))
+}
+
+fn validate_address(field: &str, address: &str, chain_id: Option<i64>) -> Result<()> {
+    let valid = match chain_id.map(address_family) {
+        Some(Some(AddressFamily::Evm)) => is_evm_address(address),
+        Some(Some(AddressFamily::Tron)) => is_tron_address(address),
+        Some(None) => true,
+        None => {
+            is_evm_address(address)
+                || is_solana_address(address)
+                || is_tron_address(address)
+                || is_stellar_address(address)
+        }
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        let chain =
+            chain_id.map_or_else(|| "default chain".to_string(), |id| format!("chain {id}"));
+        Err(RainError::ValidationError(format!(
+            "{field} {address:?} is not a valid address for {chain}"
+        )))
+    }
+}
+
+/// Address format used by a chain
+enum AddressFamily {
+    Evm,
+    Tron,
+}
+
+/// The address format of a known chain, or `None` for a chain this SDK does not know
+fn address_family(chain_id: i64) -> Option<AddressFamily> {
+    match chain_id {
+        // Ethereum, Optimism, BNB Chain, Polygon, Base, Arbitrum, Avalanche and testnets
+        1 | 10 | 56 | 137 | 8453 | 42161 | 43114 | 80002 | 84532 | 421614 | 11155111 => {
+            Some(AddressFamily::Evm)
+        }
+        // Tron mainnet, Shasta and Nile
+        728126428 | 2494104990 | 3448148188 => Some(AddressFamily::Tron),
+        _ => None,
+    }
+}
+
+fn is_evm_address(address: &str) -> bool {
+    address
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn is_base58(address: &str) -> bool {
+    address
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+}
+
+fn is_solana_address(address: &str) -> bool {
+    (32..=44).contains(&address.len()) && is_base58(address)
+}
+
+fn is_tron_address(address: &str) -> bool {
+    address.len() == 34 && address.starts_with('T') && is_base58(address)
+}
+
+fn is_stellar_address(address: &str) -> bool {
+    address.len() == 56
+        && address.starts_with('G')
+        && address
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || ('2'..='7').contains(&c))
+}
@@ -12,6 +12,83 @@ pub struct ApplicationCompletionLink {
     pub params: Value,
 }
 
+impl ApplicationCompletionLink {
+    /// Get the full URL to send the subtenant to, with `params` added as the query
+    ///
+    /// String parameters are added as they are and other values in their JSON
+    /// form; `null` parameters are left out. Returns `None` if `url` is not a valid
+    /// absolute URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::subtenants::ApplicationCompletionLink;
+    /// use serde_json::json;
+    ///
+    /// let link = ApplicationCompletionLink {
+    ///     url: "https://apply.example.com/subtenant".to_string(),
+    ///     params: json!({ "subtenantId": "9c0d1e2f", "step": 2, "skip": null }),
+    /// };
+    /// assert_eq!(
+    ///     link.to_url().unwrap().as_str(),
+    ///     "https://apply.example.com/subtenant?step=2&subtenantId=9c0d1e2f"
+    /// );
+    ///
+    /// let invalid = ApplicationCompletionLink {
+    ///     url: "not a url".to_string(),
+    ///     params: json!({}),
+    /// };
+    /// assert!(invalid.to_url().is_none());
+    /// ```
+    pub fn to_url(&self) -> Option<url::Url> {
+        let mut url = url::Url::parse(&self.url).ok()?;
+        if let Value::Object(params) = &self.params {
+            let mut query = url.query_pairs_mut();
+            for (key, value) in params {
+                match value {
+                    Value::Null => {}
+                    Value::String(value) => {
+                        query.append_pair(key, value);
+                    }
+                    value => {
+                        query.append_pair(key, &value.to_string());
+                    }
+                }
+            }
+        }
+        Some(url)
+    }
+
+    /// Get the completion URL with a link back to `return_to` once done
+    ///
+    /// `return_to` is appended as the query parameter `key`, the name the hosted
+    /// flow reads its redirect target from.
+    ///
+    /// Returns `None` if `url` is not a valid absolute URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::subtenants::ApplicationCompletionLink;
+    /// use serde_json::json;
+    ///
+    /// let link = ApplicationCompletionLink {
+    ///     url: "https://apply.example.com/subtenant".to_string(),
+    ///     params: json!({ "subtenantId": "9c0d1e2f" }),
+    /// };
+    /// assert_eq!(
+    ///     link.redirect_url("redirect", "https://yourapp.com/return?from=rain").unwrap().as_str(),
+    ///     "https://apply.example.com/subtenant?subtenantId=9c0d1e2f\
+    ///      &redirect=https%3A%2F%2Fyourapp.com%2Freturn%3Ffrom%3Drain"
+    /// );
+    /// ```
+    pub fn redirect_url(&self, key: &str, return_to: &str) -> Option<url::Url> {
+        let mut url = self.to_url()?;
+        url.query_pairs_mut().append_pair(key, return_to);
+        Some(url)
+    }
+}
+
 /// Subtenant information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,6 +99,40 @@ pub struct Subtenant {
     pub application_completion_link: Option<ApplicationCompletionLink>,
 }
 
+impl Subtenant {
+    /// Get the URL that completes the subtenant's application, if one is pending
+    ///
+    /// Shorthand for [`ApplicationCompletionLink::redirect_url`] on
+    /// `application_completion_link`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::subtenants::Subtenant;
+    ///
+    /// let subtenant: Subtenant = serde_json::from_str(
+    ///     r#"{"id":"9c0d1e2f-3a4b-4c5d-8e6f-8a9b0c1d2e3f","name":"Partner","applicationCompletionLink":{"url":"https://apply.example.com","params":{"subtenantId":"9c0d1e2f-3a4b-4c5d-8e6f-8a9b0c1d2e3f"}}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     subtenant.completion_url("returnUrl", "https://yourapp.com/done").unwrap().as_str(),
+    ///     "https://apply.example.com/?subtenantId=9c0d1e2f-3a4b-4c5d-8e6f-8a9b0c1d2e3f\
+    ///      &returnUrl=https%3A%2F%2Fyourapp.com%2Fdone"
+    /// );
+    ///
+    /// let done: Subtenant = serde_json::from_str(
+    ///     r#"{"id":"9c0d1e2f-3a4b-4c5d-8e6f-8a9b0c1d2e3f","name":"Partner"}"#,
+    /// )
+    /// .unwrap();
+    /// assert!(done.completion_url("returnUrl", "https://yourapp.com/done").is_none());
+    /// ```
+    pub fn completion_url(&self, key: &str, return_to: &str) -> Option<url::Url> {
+        self.application_completion_link
+            .as_ref()?
+            .redirect_url(key, return_to)
+    }
+}
+
 /// Request to create a subtenant
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
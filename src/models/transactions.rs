@@ -1,12 +1,16 @@
 //! Models for transaction endpoints
 
+use crate::error::RainError;
 use crate::models::cards::CardType;
+use crate::models::common::{de_amount, de_option_amount, de_token_amount, Amount, TokenAmount};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use uuid::Uuid;
 
 /// Transaction type
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum TransactionType {
     Spend,
@@ -15,8 +19,21 @@ pub enum TransactionType {
     Fee,
 }
 
+impl TransactionType {
+    /// Wire representation of the type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Spend => "spend",
+            TransactionType::Collateral => "collateral",
+            TransactionType::Payment => "payment",
+            TransactionType::Fee => "fee",
+        }
+    }
+}
+
 /// Spend transaction status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum SpendTransactionStatus {
     Pending,
@@ -27,24 +44,186 @@ pub enum SpendTransactionStatus {
 
 /// Payment transaction status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum PaymentTransactionStatus {
     Pending,
     Completed,
 }
 
+/// Why a spend transaction was declined
+///
+/// Parsed from the `declinedReason` field. Rain publishes no list of codes for
+/// that field, which may hold free text, so the named variants are best-effort:
+/// they match only if the server sends exactly the camelCase code shown on each
+/// variant, and a spend declined for one of these causes may well arrive as
+/// [`DeclinedReason::Other`] with a human-readable message instead. Do not rely
+/// on a named variant being returned; show [`DeclinedReason::as_str`] when no
+/// variant matches.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::transactions::DeclinedReason;
+///
+/// for (reason, wire) in [
+///     (DeclinedReason::InsufficientFunds, "\"insufficientFunds\""),
+///     (DeclinedReason::CardLocked, "\"cardLocked\""),
+///     (DeclinedReason::CardCanceled, "\"cardCanceled\""),
+///     (DeclinedReason::LimitExceeded, "\"limitExceeded\""),
+///     (DeclinedReason::MerchantBlocked, "\"merchantBlocked\""),
+/// ] {
+///     assert_eq!(serde_json::from_str::<DeclinedReason>(wire).unwrap(), reason);
+///     assert_eq!(serde_json::to_string(&reason).unwrap(), wire);
+/// }
+///
+/// let other: DeclinedReason = serde_json::from_str("\"Suspected fraud\"").unwrap();
+/// assert_eq!(other, DeclinedReason::Other("Suspected fraud".to_string()));
+/// assert_eq!(serde_json::to_string(&other).unwrap(), "\"Suspected fraud\"");
+///
+/// // Spend transactions expose their declined reason typed
+/// use rain_sdk::models::transactions::SpendTransaction;
+///
+/// let spend: SpendTransaction = serde_json::from_value(serde_json::json!({
+///     "amount": 1000,
+///     "currency": "USD",
+///     "receipt": false,
+///     "merchantName": "Coffee Shop",
+///     "merchantCategory": "Restaurants",
+///     "merchantCategoryCode": "5814",
+///     "cardId": uuid::Uuid::new_v4(),
+///     "cardType": "virtual",
+///     "userId": uuid::Uuid::new_v4(),
+///     "userFirstName": "Ada",
+///     "userEmail": "ada@example.com",
+///     "status": "declined",
+///     "declinedReason": "limitExceeded",
+///     "authorizedAt": "2024-01-01T00:00:00Z"
+/// }))
+/// .unwrap();
+/// assert_eq!(spend.declined_reason_typed(), Some(DeclinedReason::LimitExceeded));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum DeclinedReason {
+    /// The balance does not cover the spend (`insufficientFunds`, best-effort)
+    InsufficientFunds,
+    /// The card is locked (`cardLocked`, best-effort)
+    CardLocked,
+    /// The card is canceled (`cardCanceled`, best-effort)
+    CardCanceled,
+    /// The spend goes over a card or user limit (`limitExceeded`, best-effort)
+    LimitExceeded,
+    /// The merchant or its category is blocked for the card (`merchantBlocked`,
+    /// best-effort)
+    MerchantBlocked,
+    /// Any other reason, including free-text messages, as sent by the server
+    Other(String),
+}
+
+impl DeclinedReason {
+    /// Wire representation of the reason
+    pub fn as_str(&self) -> &str {
+        match self {
+            DeclinedReason::InsufficientFunds => "insufficientFunds",
+            DeclinedReason::CardLocked => "cardLocked",
+            DeclinedReason::CardCanceled => "cardCanceled",
+            DeclinedReason::LimitExceeded => "limitExceeded",
+            DeclinedReason::MerchantBlocked => "merchantBlocked",
+            DeclinedReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<String> for DeclinedReason {
+    fn from(reason: String) -> Self {
+        match reason.as_str() {
+            "insufficientFunds" => DeclinedReason::InsufficientFunds,
+            "cardLocked" => DeclinedReason::CardLocked,
+            "cardCanceled" => DeclinedReason::CardCanceled,
+            "limitExceeded" => DeclinedReason::LimitExceeded,
+            "merchantBlocked" => DeclinedReason::MerchantBlocked,
+            _ => DeclinedReason::Other(reason),
+        }
+    }
+}
+
+impl From<DeclinedReason> for String {
+    fn from(reason: DeclinedReason) -> Self {
+        reason.as_str().to_string()
+    }
+}
+
+impl fmt::Display for DeclinedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Spend transaction details
+///
+/// `authorized_at` and `posted_at` are parsed RFC 3339 timestamps. They were plain
+/// strings in earlier releases; code that parsed them itself can use the values
+/// directly, or call `to_rfc3339()` where a string is still needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use rain_sdk::models::transactions::SpendTransaction;
+///
+/// let json = r#"{
+///     "amount": 1250,
+///     "currency": "USD",
+///     "receipt": false,
+///     "merchantName": "Coffee Shop",
+///     "merchantCategory": "Restaurants",
+///     "merchantCategoryCode": "5814",
+///     "cardId": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12",
+///     "cardType": "virtual",
+///     "userId": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b13",
+///     "userFirstName": "Ada",
+///     "userEmail": "ada@example.com",
+///     "status": "completed",
+///     "authorizedAt": "2024-01-15T10:30:00.123Z",
+///     "postedAt": "2024-01-16T00:00:00.000Z"
+/// }"#;
+/// let spend: SpendTransaction = serde_json::from_str(json).unwrap();
+/// assert_eq!(
+///     spend.authorized_at,
+///     Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap() + chrono::Duration::milliseconds(123)
+/// );
+/// assert_eq!(spend.posted_at, Some(Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap()));
+///
+/// let round_trip: SpendTransaction =
+///     serde_json::from_str(&serde_json::to_string(&spend).unwrap()).unwrap();
+/// assert_eq!(round_trip.authorized_at, spend.authorized_at);
+/// assert_eq!(round_trip.posted_at, spend.posted_at);
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SpendTransaction {
-    pub amount: i64,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub amount: Amount,
     pub currency: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub local_amount: Option<i64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "de_option_amount"
+    )]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = Option<String>))]
+    pub local_amount: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub local_currency: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorized_amount: Option<i64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "de_option_amount"
+    )]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = Option<String>))]
+    pub authorized_amount: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authorization_method: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,16 +252,52 @@ pub struct SpendTransaction {
     pub status: SpendTransactionStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub declined_reason: Option<String>,
-    pub authorized_at: String,
+    pub authorized_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub posted_at: Option<String>,
+    pub posted_at: Option<DateTime<Utc>>,
+}
+
+impl SpendTransaction {
+    /// Get the declined reason as a typed [`DeclinedReason`]
+    pub fn declined_reason_typed(&self) -> Option<DeclinedReason> {
+        self.declined_reason.clone().map(DeclinedReason::from)
+    }
 }
 
 /// Collateral transaction details
+///
+/// Unlike spend, payment, and fee amounts (integer cents), collateral amounts are
+/// denominated in token units and may be fractional, so `amount` is a
+/// [`TokenAmount`]: `f64` by default, or an exact `Decimal` serialized as a string
+/// with the `decimal` feature. It deserializes from either a JSON number or a
+/// numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::transactions::CollateralTransaction;
+///
+/// let json = r#"{
+///     "amount": 1234.56,
+///     "currency": "USDC",
+///     "chainId": 1,
+///     "walletAddress": "0x1111111111111111111111111111111111111111",
+///     "transactionHash": "0xabc"
+/// }"#;
+/// let collateral: CollateralTransaction = serde_json::from_str(json).unwrap();
+/// assert_eq!(collateral.amount.to_string(), "1234.56");
+///
+/// let round_trip: CollateralTransaction =
+///     serde_json::from_str(&serde_json::to_string(&collateral).unwrap()).unwrap();
+/// assert_eq!(round_trip.amount.to_string(), "1234.56");
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CollateralTransaction {
-    pub amount: f64,
+    #[serde(deserialize_with = "de_token_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub amount: TokenAmount,
     pub currency: String,
     pub chain_id: i64,
     pub wallet_address: String,
@@ -99,9 +314,12 @@ pub struct CollateralTransaction {
 
 /// Payment transaction details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentTransaction {
-    pub amount: i64,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub amount: Amount,
     pub currency: String,
     pub status: PaymentTransactionStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,14 +335,17 @@ pub struct PaymentTransaction {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub posted_at: Option<String>,
+    pub posted_at: Option<DateTime<Utc>>,
 }
 
 /// Fee transaction details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FeeTransaction {
-    pub amount: i64,
+    #[serde(deserialize_with = "de_amount")]
+    #[cfg_attr(all(feature = "axum", feature = "decimal"), schema(value_type = String))]
+    pub amount: Amount,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -137,6 +358,7 @@ pub struct FeeTransaction {
 
 /// Transaction (discriminated union based on type)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[allow(clippy::large_enum_variant)]
 pub enum Transaction {
@@ -166,8 +388,204 @@ pub enum Transaction {
     },
 }
 
+impl Transaction {
+    /// Get the transaction id, whatever its type
+    pub fn id(&self) -> &Uuid {
+        match self {
+            Transaction::Spend { id, .. }
+            | Transaction::Collateral { id, .. }
+            | Transaction::Payment { id, .. }
+            | Transaction::Fee { id, .. } => id,
+        }
+    }
+
+    /// Get when the transaction posted, whatever its type
+    pub fn posted_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Transaction::Spend { spend, .. } => spend.posted_at,
+            Transaction::Collateral { collateral, .. } => collateral.posted_at,
+            Transaction::Payment { payment, .. } => payment.posted_at,
+            Transaction::Fee { fee, .. } => fee.posted_at,
+        }
+    }
+}
+
+/// Sum the completed spend amounts in `currency`
+///
+/// Pending, reversed and declined spends are skipped, as are collateral,
+/// payment and fee transactions. The currency comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::common::Amount;
+/// use rain_sdk::models::transactions::{transactions_total_spend, Transaction};
+///
+/// let spend = |amount: i64, currency: &str, status: &str| -> Transaction {
+///     serde_json::from_value(serde_json::json!({
+///         "id": uuid::Uuid::new_v4(),
+///         "type": "spend",
+///         "amount": amount,
+///         "currency": currency,
+///         "receipt": false,
+///         "merchantName": "Coffee Shop",
+///         "merchantCategory": "Restaurants",
+///         "merchantCategoryCode": "5814",
+///         "cardId": uuid::Uuid::new_v4(),
+///         "cardType": "virtual",
+///         "userId": uuid::Uuid::new_v4(),
+///         "userFirstName": "Ada",
+///         "userEmail": "ada@example.com",
+///         "status": status,
+///         "authorizedAt": "2024-01-01T00:00:00Z"
+///     }))
+///     .unwrap()
+/// };
+/// let fee: Transaction = serde_json::from_value(serde_json::json!({
+///     "id": uuid::Uuid::new_v4(),
+///     "type": "fee",
+///     "amount": 99
+/// }))
+/// .unwrap();
+///
+/// let transactions = vec![
+///     spend(1_000, "USD", "completed"),
+///     spend(250, "usd", "completed"),
+///     spend(400, "USD", "reversed"),
+///     spend(700, "USD", "declined"),
+///     spend(300, "USD", "pending"),
+///     spend(5_000, "EUR", "completed"),
+///     fee,
+/// ];
+/// assert_eq!(transactions_total_spend(&transactions, "USD"), Amount::from(1_250));
+/// assert_eq!(transactions_total_spend(&transactions, "EUR"), Amount::from(5_000));
+/// assert_eq!(transactions_total_spend(&transactions, "GBP"), Amount::from(0));
+/// ```
+pub fn transactions_total_spend(transactions: &[Transaction], currency: &str) -> Amount {
+    transactions
+        .iter()
+        .filter_map(|transaction| match transaction {
+            Transaction::Spend { spend, .. }
+                if spend.status == SpendTransactionStatus::Completed
+                    && spend.currency.eq_ignore_ascii_case(currency) =>
+            {
+                Some(spend.amount)
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+/// Keep the spend transactions whose merchant name contains `query`
+///
+/// The API has no merchant search, so this filters a fetched list. Matching is
+/// case-insensitive and checks both `merchant_name` and `enriched_merchant_name`.
+/// Non-spend transactions never match, and an empty query matches every spend.
+///
+/// # Examples
+///
+/// ```rust
+/// use rain_sdk::models::transactions::{filter_by_merchant, Transaction};
+///
+/// let spend = |merchant: &str, enriched: Option<&str>| -> Transaction {
+///     serde_json::from_value(serde_json::json!({
+///         "id": uuid::Uuid::new_v4(),
+///         "type": "spend",
+///         "amount": 1000,
+///         "currency": "USD",
+///         "receipt": false,
+///         "merchantName": merchant,
+///         "enrichedMerchantName": enriched,
+///         "merchantCategory": "Restaurants",
+///         "merchantCategoryCode": "5814",
+///         "cardId": uuid::Uuid::new_v4(),
+///         "cardType": "virtual",
+///         "userId": uuid::Uuid::new_v4(),
+///         "userFirstName": "Ada",
+///         "userEmail": "ada@example.com",
+///         "status": "completed",
+///         "authorizedAt": "2024-01-01T00:00:00Z"
+///     }))
+///     .unwrap()
+/// };
+/// let fee: Transaction = serde_json::from_value(serde_json::json!({
+///     "id": uuid::Uuid::new_v4(),
+///     "type": "fee",
+///     "amount": 99,
+///     "description": "Starbucks card fee"
+/// }))
+/// .unwrap();
+///
+/// let transactions = vec![
+///     spend("STARBUCKS #1234 SEATTLE", None),
+///     spend("SQ *BLUE BOTTLE", Some("Blue Bottle Coffee")),
+///     spend("AMZN MKTP US", Some("Amazon")),
+///     fee,
+/// ];
+/// let ids: Vec<_> = transactions.iter().map(|t| *t.id()).collect();
+/// let matches = |query: &str| -> Vec<_> {
+///     filter_by_merchant(&transactions, query)
+///         .into_iter()
+///         .map(|t| *t.id())
+///         .collect()
+/// };
+/// assert_eq!(matches("starbucks"), vec![ids[0]]);
+/// assert_eq!(matches("coffee"), vec![ids[1]]);
+/// assert_eq!(matches("AMAZON"), vec![ids[2]]);
+/// assert!(matches("walmart").is_empty());
+/// assert_eq!(matches(""), ids[..3].to_vec());
+/// ```
+pub fn filter_by_merchant<'a>(
+    transactions: &'a [Transaction],
+    query: &str,
+) -> Vec<&'a Transaction> {
+    let query = query.to_lowercase();
+    transactions
+        .iter()
+        .filter(|transaction| match transaction {
+            Transaction::Spend { spend, .. } => std::iter::once(&spend.merchant_name)
+                .chain(&spend.enriched_merchant_name)
+                .any(|name| name.to_lowercase().contains(&query)),
+            _ => false,
+        })
+        .collect()
+}
+
 /// Query parameters for listing transactions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Date filters are sent as RFC 3339 timestamps.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use rain_sdk::models::transactions::{ListTransactionsParams, TransactionType};
+///
+/// let params = ListTransactionsParams {
+///     authorized_before: Some(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()),
+///     posted_after: Some(Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap()),
+///     ..Default::default()
+/// };
+/// let query = params.to_query_string().unwrap();
+/// assert_eq!(
+///     query,
+///     "authorizedBefore=2024-01-02T03%3A04%3A05Z&postedAfter=2023-12-31T00%3A00%3A00Z&limit=20"
+/// );
+///
+/// // Several types are sent as one comma-separated `type` value, in the query
+/// // string and in JSON alike
+/// let params = ListTransactionsParams {
+///     transaction_type: Some(vec![TransactionType::Spend, TransactionType::Fee]),
+///     limit: Some(25),
+///     ..Default::default()
+/// };
+/// assert_eq!(params.to_query_string().unwrap(), "type=spend%2Cfee&limit=25");
+/// assert_eq!(
+///     serde_json::to_value(&params).unwrap(),
+///     serde_json::json!({"type": "spend,fee", "limit": 25})
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListTransactionsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -176,10 +594,11 @@ pub struct ListTransactionsParams {
     pub user_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card_id: Option<Uuid>,
+    /// Sent as one comma-separated `type` value
     #[serde(
         rename = "type",
         skip_serializing_if = "Option::is_none",
-        serialize_with = "serialize_transaction_types"
+        serialize_with = "ser_transaction_types"
     )]
     pub transaction_type: Option<Vec<TransactionType>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -194,34 +613,186 @@ pub struct ListTransactionsParams {
     pub posted_after: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
-fn serialize_transaction_types<S>(
+impl ListTransactionsParams {
+    /// Create a builder for transaction list parameters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use rain_sdk::models::transactions::{ListTransactionsParams, TransactionType};
+    /// use uuid::Uuid;
+    ///
+    /// let company_id = Uuid::new_v4();
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    ///
+    /// let params = ListTransactionsParams::builder()
+    ///     .company_id(company_id)
+    ///     .posted_between(start, end)
+    ///     .types([TransactionType::Spend, TransactionType::Fee])
+    ///     .limit(50)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.company_id, Some(company_id));
+    /// assert_eq!(params.posted_after, Some(start));
+    /// assert_eq!(params.posted_before, Some(end));
+    /// assert_eq!(
+    ///     params.transaction_type,
+    ///     Some(vec![TransactionType::Spend, TransactionType::Fee])
+    /// );
+    /// assert_eq!(params.limit, Some(50));
+    ///
+    /// // A range that ends before it starts is rejected before any request is made
+    /// let err = ListTransactionsParams::builder()
+    ///     .posted_between(end, start)
+    ///     .build();
+    /// assert!(err.is_err());
+    /// ```
+    pub fn builder() -> ListTransactionsParamsBuilder {
+        ListTransactionsParamsBuilder::default()
+    }
+
+    /// Encode the parameters as a query string
+    pub fn to_query_string(&self) -> Result<String, RainError> {
+        crate::client::encode_query(self)
+    }
+}
+
+/// Serialize transaction types as one comma-separated string
+fn ser_transaction_types<S>(
     types: &Option<Vec<TransactionType>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
-    use serde::ser::SerializeSeq;
-
-    match types {
-        Some(ref vec) => {
-            let mut seq = serializer.serialize_seq(Some(vec.len()))?;
-            for item in vec {
-                let s = match item {
-                    TransactionType::Spend => "spend",
-                    TransactionType::Collateral => "collateral",
-                    TransactionType::Payment => "payment",
-                    TransactionType::Fee => "fee",
-                };
-                seq.serialize_element(s)?;
-            }
-            seq.end()
-        }
-        None => serializer.serialize_none(),
+    types
+        .iter()
+        .flatten()
+        .map(TransactionType::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+        .serialize(serializer)
+}
+
+/// Builder for [`ListTransactionsParams`]
+#[derive(Debug, Clone, Default)]
+pub struct ListTransactionsParamsBuilder {
+    params: ListTransactionsParams,
+}
+
+impl ListTransactionsParamsBuilder {
+    /// Only list transactions of this company
+    pub fn company_id(mut self, company_id: Uuid) -> Self {
+        self.params.company_id = Some(company_id);
+        self
+    }
+
+    /// Only list transactions of this user
+    pub fn user_id(mut self, user_id: Uuid) -> Self {
+        self.params.user_id = Some(user_id);
+        self
+    }
+
+    /// Only list transactions made with this card
+    pub fn card_id(mut self, card_id: Uuid) -> Self {
+        self.params.card_id = Some(card_id);
+        self
+    }
+
+    /// Only list transactions of these types
+    pub fn types(mut self, types: impl IntoIterator<Item = TransactionType>) -> Self {
+        self.params.transaction_type = Some(types.into_iter().collect());
+        self
+    }
+
+    /// Only list transactions with this on-chain hash
+    pub fn transaction_hash(mut self, transaction_hash: impl Into<String>) -> Self {
+        self.params.transaction_hash = Some(transaction_hash.into());
+        self
+    }
+
+    /// Only list transactions authorized before this time
+    pub fn authorized_before(mut self, before: DateTime<Utc>) -> Self {
+        self.params.authorized_before = Some(before);
+        self
+    }
+
+    /// Only list transactions authorized after this time
+    pub fn authorized_after(mut self, after: DateTime<Utc>) -> Self {
+        self.params.authorized_after = Some(after);
+        self
+    }
+
+    /// Only list transactions authorized between `start` and `end`
+    pub fn authorized_between(self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.authorized_after(start).authorized_before(end)
+    }
+
+    /// Only list transactions posted before this time
+    pub fn posted_before(mut self, before: DateTime<Utc>) -> Self {
+        self.params.posted_before = Some(before);
+        self
+    }
+
+    /// Only list transactions posted after this time
+    pub fn posted_after(mut self, after: DateTime<Utc>) -> Self {
+        self.params.posted_after = Some(after);
+        self
+    }
+
+    /// Only list transactions posted between `start` and `end`
+    pub fn posted_between(self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.posted_after(start).posted_before(end)
+    }
+
+    /// Continue from a cursor returned by a previous page
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.params.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Set the page size
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Build and validate the parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RainError::ValidationError`] if the authorized or posted range
+    /// ends before it starts.
+    pub fn build(self) -> Result<ListTransactionsParams, RainError> {
+        let params = self.params;
+        check_range(
+            "authorized",
+            params.authorized_after,
+            params.authorized_before,
+        )?;
+        check_range("posted", params.posted_after, params.posted_before)?;
+        Ok(params)
+    }
+}
+
+fn check_range(
+    field: &str,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+) -> Result<(), RainError> {
+    match (after, before) {
+        (Some(after), Some(before)) if after > before => Err(RainError::ValidationError(format!(
+            "{field} range starts at {} but ends earlier at {}",
+            after.to_rfc3339(),
+            before.to_rfc3339()
+        ))),
+        _ => Ok(()),
     }
 }
 
@@ -6,6 +6,7 @@ use uuid::Uuid;
 
 /// User information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct User {
     pub id: Uuid,
@@ -62,6 +63,78 @@ pub struct CreateCompanyUserRequest {
     pub phone_number: Option<String>,
 }
 
+impl CreateCompanyUserRequest {
+    /// Build a request for a company user from the initial user of a company application
+    ///
+    /// Copies the name, email, terms acceptance, birth date, address, phone and
+    /// EVM and Solana wallets. The request has no place for the remaining fields, so
+    /// the id, role, national id, country of issue, IP address and Tron and Stellar
+    /// addresses are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::applications::InitialUser;
+    /// use rain_sdk::models::common::Address;
+    /// use rain_sdk::models::users::CreateCompanyUserRequest;
+    ///
+    /// let address = Address {
+    ///     line1: "1 Main St".to_string(),
+    ///     line2: None,
+    ///     city: "Springfield".to_string(),
+    ///     region: "IL".to_string(),
+    ///     postal_code: "62701".to_string(),
+    ///     country_code: "US".to_string(),
+    ///     country: None,
+    /// };
+    /// let initial_user = InitialUser {
+    ///     id: None,
+    ///     first_name: "Ada".to_string(),
+    ///     last_name: "Lovelace".to_string(),
+    ///     birth_date: "1990-12-10".to_string(),
+    ///     national_id: "123456789".to_string(),
+    ///     country_of_issue: "US".to_string(),
+    ///     email: "ada@example.com".to_string(),
+    ///     phone_country_code: Some("1".to_string()),
+    ///     phone_number: Some("5555555555".to_string()),
+    ///     address: address.clone(),
+    ///     role: Some("owner".to_string()),
+    ///     wallet_address: Some("0x1111111111111111111111111111111111111111".to_string()),
+    ///     solana_address: Some("So1ana".to_string()),
+    ///     tron_address: Some("Tron".to_string()),
+    ///     stellar_address: None,
+    ///     ip_address: "127.0.0.1".to_string(),
+    ///     is_terms_of_service_accepted: true,
+    /// };
+    ///
+    /// let request = CreateCompanyUserRequest::from_initial_user(&initial_user);
+    /// assert_eq!(request.first_name, "Ada");
+    /// assert_eq!(request.last_name, "Lovelace");
+    /// assert_eq!(request.email, "ada@example.com");
+    /// assert!(request.is_terms_of_service_accepted);
+    /// assert_eq!(request.birth_date.as_deref(), Some("1990-12-10"));
+    /// assert_eq!(request.wallet_address, initial_user.wallet_address);
+    /// assert_eq!(request.solana_address, initial_user.solana_address);
+    /// assert_eq!(request.address.unwrap().line1, address.line1);
+    /// assert_eq!(request.phone_country_code.as_deref(), Some("1"));
+    /// assert_eq!(request.phone_number.as_deref(), Some("5555555555"));
+    /// ```
+    pub fn from_initial_user(user: &crate::models::applications::InitialUser) -> Self {
+        Self {
+            first_name: user.first_name.clone(),
+            last_name: user.last_name.clone(),
+            email: user.email.clone(),
+            is_terms_of_service_accepted: user.is_terms_of_service_accepted,
+            birth_date: Some(user.birth_date.clone()),
+            wallet_address: user.wallet_address.clone(),
+            solana_address: user.solana_address.clone(),
+            address: Some(user.address.clone()),
+            phone_country_code: user.phone_country_code.clone(),
+            phone_number: user.phone_number.clone(),
+        }
+    }
+}
+
 /// Request to create an authorized user
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -89,8 +162,13 @@ pub struct UpdateUserRequest {
     pub first_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    /// `None` leaves the email unchanged, `Some(None)` clears it
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::models::common::double_option"
+    )]
+    pub email: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,6 +189,12 @@ pub struct UpdateUserRequest {
     pub stellar_address: Option<String>,
 }
 
+impl_with_phone!(
+    CreateCompanyUserRequest,
+    CreateUserRequest,
+    UpdateUserRequest
+);
+
 /// Query parameters for listing users
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -119,9 +203,11 @@ pub struct ListUsersParams {
     pub company_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
 
 /// Response for list of users (just an array of users)
 pub type ListUsersResponse = Vec<User>;
+
+impl_application_reason!(User);
@@ -1,6 +1,10 @@
 //! Models for webhook endpoints
 
-use chrono::{DateTime, Utc};
+use crate::clock::Clock;
+use crate::error::{RainError, Result};
+use crate::models::cards::Card;
+use crate::models::transactions::Transaction;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -36,6 +40,188 @@ pub struct ListWebhooksParams {
     pub response_received_at_after: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::models::common::ser_page_limit")]
     pub limit: Option<u32>,
 }
+
+/// Check that a webhook was sent within `tolerance` of the current time
+///
+/// Rejects deliveries older than the tolerance, which guards against replays,
+/// and deliveries stamped further than the tolerance in the future. The current
+/// time comes from `clock`, typically [`SystemClock`](crate::SystemClock) or the
+/// clock given to [`Config::with_clock`](crate::Config::with_clock).
+///
+/// # Errors
+///
+/// Returns [`RainError::ValidationError`] if the timestamp is outside the tolerance.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rain_sdk::clock::FixedClock;
+/// use rain_sdk::models::webhooks::check_webhook_timestamp;
+///
+/// let clock = FixedClock(Utc.with_ymd_and_hms(2024, 1, 1, 12, 5, 0).unwrap());
+/// let tolerance = Duration::minutes(5);
+///
+/// let on_edge = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// assert!(check_webhook_timestamp(on_edge, tolerance, &clock).is_ok());
+///
+/// let stale = Utc.with_ymd_and_hms(2024, 1, 1, 11, 59, 59).unwrap();
+/// assert!(check_webhook_timestamp(stale, tolerance, &clock).is_err());
+///
+/// let future = Utc.with_ymd_and_hms(2024, 1, 1, 12, 10, 1).unwrap();
+/// assert!(check_webhook_timestamp(future, tolerance, &clock).is_err());
+/// ```
+pub fn check_webhook_timestamp(
+    sent_at: DateTime<Utc>,
+    tolerance: Duration,
+    clock: &dyn Clock,
+) -> Result<()> {
+    let skew = clock.now() - sent_at;
+    if skew > tolerance {
+        Err(RainError::ValidationError(format!(
+            "Webhook was sent {}s ago, beyond the {}s tolerance",
+            skew.num_seconds(),
+            tolerance.num_seconds()
+        )))
+    } else if -skew > tolerance {
+        Err(RainError::ValidationError(format!(
+            "Webhook is stamped {}s in the future, beyond the {}s tolerance",
+            (-skew).num_seconds(),
+            tolerance.num_seconds()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// A webhook event delivered by Rain
+///
+/// Payloads have the shape `{"id", "resource", "action", "body"}`; the event type
+/// is `"{resource}.{action}"`. Event types the SDK does not model yet are kept as
+/// [`WebhookEvent::UnknownEvent`] with the full payload.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum WebhookEvent {
+    /// A card was created
+    CardCreated { id: Uuid, card: Card },
+    /// A card was updated
+    CardUpdated { id: Uuid, card: Card },
+    /// A transaction was created
+    TransactionCreated { id: Uuid, transaction: Transaction },
+    /// A transaction was updated
+    TransactionUpdated { id: Uuid, transaction: Transaction },
+    /// An event type not modeled by the SDK
+    UnknownEvent { event_type: String, raw: Value },
+}
+
+/// Envelope shared by all webhook payloads
+#[derive(Debug, Deserialize)]
+struct WebhookEnvelope {
+    id: Uuid,
+    body: Value,
+}
+
+impl WebhookEvent {
+    /// Parse a webhook payload without verifying its signature
+    ///
+    /// Intended for tests and local tooling; production handlers should verify
+    /// the payload before trusting it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rain_sdk::models::webhooks::WebhookEvent;
+    ///
+    /// let payload = br#"{
+    ///     "id": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b10",
+    ///     "resource": "card",
+    ///     "action": "updated",
+    ///     "body": {
+    ///         "id": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11",
+    ///         "userId": "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12",
+    ///         "type": "virtual",
+    ///         "status": "locked",
+    ///         "last4": "1234",
+    ///         "expirationMonth": "3",
+    ///         "expirationYear": "2027"
+    ///     }
+    /// }"#;
+    /// let event = WebhookEvent::from_json(payload).unwrap();
+    /// assert_eq!(event.event_type(), "card.updated");
+    /// match event {
+    ///     WebhookEvent::CardUpdated { card, .. } => assert_eq!(card.last4, "1234"),
+    ///     other => panic!("unexpected event: {other:?}"),
+    /// }
+    ///
+    /// let payload = br#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b10","resource":"contract","action":"deployed","body":{"chainId":1}}"#;
+    /// match WebhookEvent::from_json(payload).unwrap() {
+    ///     WebhookEvent::UnknownEvent { event_type, raw } => {
+    ///         assert_eq!(event_type, "contract.deployed");
+    ///         assert_eq!(raw["body"]["chainId"], 1);
+    ///     }
+    ///     other => panic!("unexpected event: {other:?}"),
+    /// }
+    /// ```
+    pub fn from_json(payload: &[u8]) -> Result<Self> {
+        let raw: Value = serde_json::from_slice(payload)?;
+        let field = |name: &str| {
+            raw.get(name)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    RainError::ValidationError(format!("Webhook payload is missing \"{name}\""))
+                })
+        };
+        let event_type = format!("{}.{}", field("resource")?, field("action")?);
+
+        let parse = || serde_json::from_value::<WebhookEnvelope>(raw.clone());
+        let event = match event_type.as_str() {
+            "card.created" | "card.updated" => {
+                let envelope = parse()?;
+                let card = serde_json::from_value(envelope.body)?;
+                if event_type == "card.created" {
+                    WebhookEvent::CardCreated {
+                        id: envelope.id,
+                        card,
+                    }
+                } else {
+                    WebhookEvent::CardUpdated {
+                        id: envelope.id,
+                        card,
+                    }
+                }
+            }
+            "transaction.created" | "transaction.updated" => {
+                let envelope = parse()?;
+                let transaction = serde_json::from_value(envelope.body)?;
+                if event_type == "transaction.created" {
+                    WebhookEvent::TransactionCreated {
+                        id: envelope.id,
+                        transaction,
+                    }
+                } else {
+                    WebhookEvent::TransactionUpdated {
+                        id: envelope.id,
+                        transaction,
+                    }
+                }
+            }
+            _ => WebhookEvent::UnknownEvent { event_type, raw },
+        };
+        Ok(event)
+    }
+
+    /// Get the event type, e.g. `"card.updated"`
+    pub fn event_type(&self) -> &str {
+        match self {
+            WebhookEvent::CardCreated { .. } => "card.created",
+            WebhookEvent::CardUpdated { .. } => "card.updated",
+            WebhookEvent::TransactionCreated { .. } => "transaction.created",
+            WebhookEvent::TransactionUpdated { .. } => "transaction.updated",
+            WebhookEvent::UnknownEvent { event_type, .. } => event_type,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Commonly used types in one import
+//!
+//! `use rain_sdk::prelude::*;` brings in the client, its configuration, the error
+//! types and the models most integrations touch. Less common models remain
+//! available under [`crate::models`].
+//!
+//! [`crate::Result`] is deliberately left out so the glob import does not shadow
+//! the standard `Result`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rain_sdk::prelude::*;
+//!
+//! fn build() -> std::result::Result<RainClient, RainError> {
+//!     let config = Config::new(Environment::Dev).with_timeout(60);
+//!     RainClient::new(config, AuthConfig::with_api_key("your-api-key".to_string()))
+//! }
+//!
+//! fn describe(transaction: &Transaction) -> String {
+//!     match transaction {
+//!         Transaction::Spend { spend, .. } if spend.status == SpendTransactionStatus::Completed => {
+//!             format!("{} {}", spend.amount, spend.currency)
+//!         }
+//!         other => format!("transaction {}", other.id()),
+//!     }
+//! }
+//!
+//! let client = build().unwrap();
+//! assert_eq!(client.base_url().as_str(), Environment::Dev.base_url().as_str());
+//!
+//! let params = ListCardsParams::default().with_statuses([CardStatus::Active]);
+//! assert_eq!(params.to_query_string().unwrap(), "status=active&limit=20");
+//!
+//! let document = DocumentUploadParams::from_base64("aGVsbG8=", "idCard", "front").unwrap();
+//! assert!(matches!(document.source, DocumentSource::Bytes { .. }));
+//!
+//! let fee: Transaction = serde_json::from_str(
+//!     r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","type":"fee","amount":99}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(describe(&fee), "transaction 6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11");
+//! ```
+
+pub use crate::auth::AuthConfig;
+pub use crate::client::bulk::BulkMode;
+pub use crate::client::wait::WaitConfig;
+pub use crate::client::RainClient;
+pub use crate::clock::{Clock, SystemClock};
+pub use crate::config::{Config, Environment};
+pub use crate::error::{ApiErrorResponse, RainError, RainErrorCode};
+#[cfg(feature = "async")]
+pub use tokio_util::sync::CancellationToken;
+
+pub use crate::models::applications::{
+    CompanyApplicationResponse, CreateCompanyApplicationRequest, CreateUserApplicationRequest,
+    DocumentSource, DocumentUploadParams, EntityInfo, InitialUser, InitiateUserApplicationRequest,
+    NextStep, Representative, UltimateBeneficialOwner, UpdateCompanyApplicationRequest,
+    UpdateUserApplicationRequest, UserApplicationResponse,
+};
+pub use crate::models::balances::BalanceResponse;
+pub use crate::models::cards::{
+    Card, CardLimit, CardStatus, CardType, CreateCardRequest, LimitFrequency, ListCardsParams,
+    UpdateCardRequest,
+};
+pub use crate::models::common::{
+    Address, Amount, ApplicationStatus, PhoneNumber, TokenAmount, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE,
+};
+pub use crate::models::companies::{Company, ListCompaniesParams};
+pub use crate::models::disputes::{CreateDisputeRequest, Dispute, DisputeReason, DisputeStatus};
+pub use crate::models::transactions::{
+    ListTransactionsParams, SpendTransactionStatus, Transaction, TransactionType,
+};
+pub use crate::models::users::{CreateUserRequest, ListUsersParams, UpdateUserRequest, User};
+pub use crate::models::webhooks::WebhookEvent;
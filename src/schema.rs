@@ -0,0 +1,204 @@
+//! JSON schema checks on request bodies, enabled by the `validate-schema` feature
+//!
+//! In debug builds, the bodies of requests with an embedded schema are checked
+//! before they are sent, so a missing field or invalid value fails with a
+//! [`RainError::ValidationError`] naming the field instead of a 400 from the
+//! server. Release builds skip the check.
+//!
+//! The schemas live in `src/schema/*.json` and use a subset of JSON Schema:
+//! `type`, `enum`, `const`, `minLength`, `maxLength`, `required`, `properties`,
+//! `anyOf`, `oneOf` and `if`/`then`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rain_sdk::{RainClient, Config, Environment, AuthConfig};
+//! use rain_sdk::models::cards::{CardType, CreateCardRequest};
+//! use rain_sdk::RainError;
+//! use uuid::Uuid;
+//!
+//! # #[cfg(feature = "async")]
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = Config::new(Environment::Dev);
+//! let auth = AuthConfig::with_api_key("your-api-key".to_string());
+//! let client = RainClient::new(config, auth)?;
+//!
+//! let user_id = Uuid::new_v4();
+//! // A physical card needs somewhere to ship to
+//! let request = CreateCardRequest {
+//!     r#type: CardType::Physical,
+//!     status: None,
+//!     limit: None,
+//!     configuration: None,
+//!     shipping: None,
+//!     bulk_shipping_group_id: None,
+//!     billing: None,
+//! };
+//! match client.create_user_card(&user_id, &request).await {
+//!     Err(RainError::ValidationError(message)) => eprintln!("{message}"),
+//!     other => println!("{other:?}"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{RainError, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Request bodies with an embedded schema
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Schema {
+    CreateCardRequest,
+    CreateUserApplicationRequest,
+}
+
+impl Schema {
+    fn name(self) -> &'static str {
+        match self {
+            Schema::CreateCardRequest => "CreateCardRequest",
+            Schema::CreateUserApplicationRequest => "CreateUserApplicationRequest",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Schema::CreateCardRequest => include_str!("schema/create_card_request.json"),
+            Schema::CreateUserApplicationRequest => {
+                include_str!("schema/create_user_application_request.json")
+            }
+        }
+    }
+}
+
+/// Check `body` against `schema` in debug builds
+pub(crate) fn check<T: Serialize>(schema: Schema, body: &T) -> Result<()> {
+    if !cfg!(debug_assertions) {
+        return Ok(());
+    }
+    let rules: Value = serde_json::from_str(schema.source()).expect("Invalid embedded schema");
+    let mut errors = Vec::new();
+    validate(&rules, &serde_json::to_value(body)?, "", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(RainError::ValidationError(format!(
+            "{} does not match its schema: {}",
+            schema.name(),
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Collect every way `value` at `path` breaks `schema`
+fn validate(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let at = if path.is_empty() { "request" } else { path };
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        if !has_type(value, expected) {
+            errors.push(format!("{at} must be a {expected}"));
+            return;
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            errors.push(format!(
+                "{at} must be one of {}",
+                Value::from(allowed.clone())
+            ));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            errors.push(format!("{at} must be {expected}"));
+        }
+    }
+    if let Some(text) = value.as_str() {
+        let length = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if length < min {
+                errors.push(format!("{at} must be at least {min} characters"));
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if length > max {
+                errors.push(format!("{at} must be at most {max} characters"));
+            }
+        }
+    }
+    if let Some(object) = value.as_object() {
+        for key in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if object.get(key).is_none_or(Value::is_null) {
+                errors.push(format!("{path}/{key} is required"));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (key, property) in properties {
+                if let Some(field) = object.get(key).filter(|field| !field.is_null()) {
+                    validate(property, field, &format!("{path}/{key}"), errors);
+                }
+            }
+        }
+    }
+    if let Some(branches) = schema.get("anyOf").and_then(Value::as_array) {
+        let failures = branch_failures(branches, value, path);
+        if failures.len() == branches.len() {
+            errors.push(format!(
+                "{at} must satisfy any of: {}",
+                failures.join(" or ")
+            ));
+        }
+    }
+    if let Some(branches) = schema.get("oneOf").and_then(Value::as_array) {
+        let failures = branch_failures(branches, value, path);
+        match branches.len() - failures.len() {
+            1 => {}
+            0 => errors.push(format!(
+                "{at} must satisfy exactly one of: {}",
+                failures.join(" or ")
+            )),
+            matched => errors.push(format!(
+                "{at} must satisfy exactly one of {} alternatives, but satisfies {matched}",
+                branches.len()
+            )),
+        }
+    }
+    if let (Some(condition), Some(then)) = (schema.get("if"), schema.get("then")) {
+        let mut unmet = Vec::new();
+        validate(condition, value, path, &mut unmet);
+        if unmet.is_empty() {
+            validate(then, value, path, errors);
+        }
+    }
+}
+
+/// The errors of each branch that `value` does not satisfy, joined per branch
+fn branch_failures(branches: &[Value], value: &Value, path: &str) -> Vec<String> {
+    branches
+        .iter()
+        .filter_map(|branch| {
+            let mut errors = Vec::new();
+            validate(branch, value, path, &mut errors);
+            (!errors.is_empty()).then(|| format!("({})", errors.join(", ")))
+        })
+        .collect()
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
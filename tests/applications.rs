@@ -0,0 +1,144 @@
+//! Application endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, fixture_with};
+use mockito::Matcher;
+use rain_sdk::models::applications::{
+    DocumentSource, DocumentUploadParams, EntityUpdateInfo, NextStep,
+};
+use rain_sdk::RainError;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+#[test]
+fn update_company_entity_sends_only_the_entity() {
+    let company_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "PATCH",
+            format!("/v1/issuing/applications/company/{company_id}").as_str(),
+        )
+        .match_body(Matcher::Json(json!({
+            "entity": {"website": "https://example.com"}
+        })))
+        .with_body(fixture_with("company_application", json!({ "id": company_id })).to_string())
+        .create();
+
+    let entity = EntityUpdateInfo::builder()
+        .website("https://example.com")
+        .build();
+    let application =
+        tokio_test::block_on(client(&server).update_company_entity(&company_id, entity)).unwrap();
+    assert_eq!(application.id, company_id);
+    mock.assert();
+}
+
+#[test]
+fn upload_representative_document_is_unsupported() {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("PUT", Matcher::Any).expect(0).create();
+
+    let company_id = Uuid::new_v4();
+    let representative_id = Uuid::new_v4();
+    let params = DocumentUploadParams {
+        document_type: "idCard".to_string(),
+        side: "front".to_string(),
+        country: Some("US".to_string()),
+        country_code: Some("US".to_string()),
+        name: None,
+        source: DocumentSource::Bytes {
+            name: "id.png".to_string(),
+            bytes: b"id".to_vec(),
+        },
+        mime_type: Some("image/png".to_string()),
+    };
+    let err = tokio_test::block_on(client(&server).upload_representative_document(
+        &company_id,
+        &representative_id,
+        &params,
+    ))
+    .unwrap_err();
+    assert!(
+        matches!(err, RainError::Unsupported(ref message) if message.contains(&representative_id.to_string()))
+    );
+    mock.assert();
+}
+
+#[test]
+fn refresh_user_verification_returns_the_next_step() {
+    let user_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "PATCH",
+            format!("/v1/issuing/applications/user/{user_id}").as_str(),
+        )
+        .match_body(Matcher::Json(json!({ "hasExistingDocuments": true })))
+        .with_body(
+            fixture_with(
+                "user_application",
+                json!({
+                    "id": user_id,
+                    "applicationStatus": "needsVerification",
+                    "applicationExternalVerificationLink": {
+                        "url": "https://verify.example.com",
+                        "params": { "userId": user_id },
+                    },
+                }),
+            )
+            .to_string(),
+        )
+        .create();
+
+    let application =
+        tokio_test::block_on(client(&server).refresh_user_verification(&user_id, true)).unwrap();
+    let expected =
+        url::Url::parse(&format!("https://verify.example.com/?userId={user_id}")).unwrap();
+    assert_eq!(application.next_step(), Some(NextStep::Verify(expected)));
+    mock.assert();
+}
+
+#[test]
+fn upload_user_document_with_progress_reports_each_chunk() {
+    let user_id = Uuid::new_v4();
+    let file_path = std::env::temp_dir().join(format!("rain-sdk-progress-{user_id}.pdf"));
+    std::fs::write(&file_path, vec![7u8; 200_000]).unwrap();
+
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock(
+            "PUT",
+            format!("/v1/issuing/applications/user/{user_id}/document").as_str(),
+        )
+        .with_body("{}")
+        .create();
+
+    let params = DocumentUploadParams {
+        document_type: "idCard".to_string(),
+        side: "front".to_string(),
+        country: None,
+        country_code: None,
+        name: None,
+        source: DocumentSource::Path(file_path.clone()),
+        mime_type: None,
+    };
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+    let result = tokio_test::block_on(client(&server).upload_user_document_with_progress(
+        &user_id,
+        &params,
+        move |sent, total| recorded.lock().unwrap().push((sent, total)),
+    ));
+    std::fs::remove_file(&file_path).unwrap();
+    result.unwrap();
+
+    let progress = progress.lock().unwrap();
+    assert!(progress.len() > 1);
+    assert!(progress.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(*progress.last().unwrap(), (200_000, 200_000));
+}
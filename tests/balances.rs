@@ -0,0 +1,117 @@
+//! Balance endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, fixture_with};
+use rain_sdk::client::bulk::BulkMode;
+use rain_sdk::models::common::Amount;
+use rain_sdk::RainError;
+use serde_json::json;
+use uuid::Uuid;
+
+/// The balance fixture with the given spending power
+fn balances(spending_power: i64) -> String {
+    fixture_with("balance", json!({ "spendingPower": spending_power })).to_string()
+}
+
+#[test]
+fn get_user_balances_bulk_keeps_each_failure_with_its_user() {
+    let user_ids = [
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    ];
+    let mut server = mockito::Server::new();
+    let mut mocks = Vec::new();
+    for (i, id) in user_ids.iter().enumerate() {
+        let mock = server.mock("GET", format!("/v1/issuing/users/{id}/balances").as_str());
+        let mock = if i == 2 {
+            mock.with_status(404)
+                .with_body(r#"{"message":"User not found"}"#)
+        } else {
+            mock.with_body(balances(i as i64))
+        };
+        mocks.push(mock.create());
+    }
+
+    let balances = tokio_test::block_on(client(&server).get_user_balances_bulk(
+        &user_ids,
+        3,
+        BulkMode::CollectAll,
+    ));
+    assert_eq!(balances.len(), 4);
+    for (i, (user_id, result)) in balances.iter().enumerate() {
+        assert_eq!(*user_id, user_ids[i]);
+        if i == 2 {
+            assert!(matches!(
+                result,
+                Err(RainError::ApiError { status: 404, .. })
+            ));
+        } else {
+            assert_eq!(
+                result.as_ref().unwrap().spending_power,
+                Amount::from(i as i64)
+            );
+        }
+    }
+}
+
+#[test]
+fn get_user_balances_bulk_fail_fast_stops_at_the_first_failure() {
+    let user_ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let mut server = mockito::Server::new();
+    let first = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/users/{}/balances", user_ids[0]).as_str(),
+        )
+        .with_body(balances(0))
+        .expect(2)
+        .create();
+    let second = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/users/{}/balances", user_ids[1]).as_str(),
+        )
+        .with_body(balances(1))
+        .expect(1)
+        .create();
+    let third = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/users/{}/balances", user_ids[2]).as_str(),
+        )
+        .with_body(balances(2))
+        .expect(1)
+        .create();
+
+    let client = client(&server);
+    let all = tokio_test::block_on(client.get_user_balances_bulk(&user_ids, 1, BulkMode::FailFast));
+    assert_eq!(all[1].0, user_ids[1]);
+    assert_eq!(all[1].1.as_ref().unwrap().spending_power, Amount::from(1));
+    second.assert();
+    third.assert();
+
+    // Once the second user is gone, the third is never fetched
+    let _deleted = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/users/{}/balances", user_ids[1]).as_str(),
+        )
+        .with_status(404)
+        .with_body(r#"{"message":"User not found"}"#)
+        .create();
+    let all = tokio_test::block_on(client.get_user_balances_bulk(&user_ids, 1, BulkMode::FailFast));
+    assert_eq!(all.len(), 3);
+    assert!(all[0].1.is_ok());
+    assert!(matches!(
+        all[1].1,
+        Err(RainError::ApiError { status: 404, .. })
+    ));
+    assert!(matches!(all[2].1, Err(RainError::Cancelled)));
+    first.assert();
+    third.assert();
+}
@@ -0,0 +1,542 @@
+//! Card endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{client, client_with, config, fixture_with};
+use mockito::Matcher;
+use rain_sdk::client::bulk::BulkMode;
+use rain_sdk::client::wait::WaitConfig;
+use rain_sdk::clock::FixedClock;
+use rain_sdk::models::cards::{
+    Card, CardSpendSummary, CardStatus, CardType, CreateCardRequest, LimitFrequency,
+    ListCardsParams, ReissueReason, UpdateCardRequest,
+};
+use rain_sdk::models::common::{Amount, DEFAULT_PAGE_SIZE};
+use rain_sdk::RainError;
+use serde_json::json;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// The card fixture with the given id and status
+fn card(id: impl ToString, status: &str) -> String {
+    fixture_with("card", json!({ "id": id.to_string(), "status": status })).to_string()
+}
+
+fn virtual_card_request() -> CreateCardRequest {
+    CreateCardRequest {
+        r#type: CardType::Virtual,
+        status: None,
+        limit: None,
+        configuration: None,
+        shipping: None,
+        bulk_shipping_group_id: None,
+        billing: None,
+    }
+}
+
+#[test]
+fn list_cards_sends_filters_as_query() {
+    let company_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/cards")
+        .match_query(Matcher::Exact(format!(
+            "companyId={company_id}&status=active&status=notActivated&limit=20"
+        )))
+        .with_body("[]")
+        .create();
+
+    let params = ListCardsParams {
+        user_id: None,
+        company_id: Some(company_id),
+        status: Some(vec![CardStatus::Active, CardStatus::NotActivated]),
+        cursor: None,
+        limit: Some(20),
+    };
+    let cards = tokio_test::block_on(client(&server).list_cards(&params)).unwrap();
+    assert!(cards.is_empty());
+    mock.assert();
+}
+
+#[test]
+fn list_user_cards() {
+    let user_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/cards")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("userId".into(), user_id.to_string()),
+            Matcher::UrlEncoded("status".into(), "active".into()),
+            Matcher::UrlEncoded("limit".into(), "10".into()),
+        ]))
+        .with_body("[]")
+        .create();
+
+    let cards = tokio_test::block_on(client(&server).list_user_cards(
+        &user_id,
+        Some(CardStatus::Active),
+        None,
+        Some(10),
+    ))
+    .unwrap();
+    assert!(cards.is_empty());
+    mock.assert();
+}
+
+#[test]
+fn list_company_cards() {
+    let company_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/cards")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("companyId".into(), company_id.to_string()),
+            Matcher::UrlEncoded("cursor".into(), "next".into()),
+        ]))
+        .with_body("[]")
+        .create();
+
+    tokio_test::block_on(client(&server).list_company_cards(&company_id, None, Some("next"), None))
+        .unwrap();
+    mock.assert();
+}
+
+#[test]
+fn wait_for_card_active_polls_until_active() {
+    let card_id = Uuid::new_v4();
+    let path = format!("/v1/issuing/cards/{card_id}");
+    let mut server = mockito::Server::new();
+    let shipped = server
+        .mock("GET", path.as_str())
+        .with_body(card(card_id, "notActivated"))
+        .expect(1)
+        .create();
+    let activated = server
+        .mock("GET", path.as_str())
+        .with_body(card(card_id, "active"))
+        .create();
+
+    let wait = WaitConfig::new().with_interval(Duration::from_millis(10));
+    let card = tokio_test::block_on(client(&server).wait_for_card_active(&card_id, &wait)).unwrap();
+    assert!(matches!(card.status, CardStatus::Active));
+    shipped.assert();
+    activated.assert();
+}
+
+#[test]
+fn get_cards_keeps_each_failure_in_its_slot() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let mut server = mockito::Server::new();
+    let mut mocks = Vec::new();
+    for (i, id) in ids.iter().enumerate() {
+        let mock = server.mock("GET", format!("/v1/issuing/cards/{id}").as_str());
+        let mock = if i == 1 {
+            mock.with_status(404)
+                .with_body(r#"{"message":"Card not found"}"#)
+        } else {
+            let body = fixture_with("card", json!({ "id": id, "last4": format!("000{i}") }));
+            mock.with_body(body.to_string())
+        };
+        mocks.push(mock.create());
+    }
+
+    let cards = tokio_test::block_on(client(&server).get_cards(&ids, 2, BulkMode::CollectAll));
+    assert_eq!(cards.len(), 3);
+    assert_eq!(cards[0].as_ref().unwrap().last4, "0000");
+    assert!(matches!(
+        cards[1],
+        Err(RainError::ApiError { status: 404, .. })
+    ));
+    assert_eq!(cards[2].as_ref().unwrap().last4, "0002");
+}
+
+#[test]
+fn get_cards_fail_fast_cancels_the_rest_of_the_batch() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let mut server = mockito::Server::new();
+    let _first = server
+        .mock("GET", format!("/v1/issuing/cards/{}", ids[0]).as_str())
+        .with_body(card(ids[0], "active"))
+        .create();
+    let _second = server
+        .mock("GET", format!("/v1/issuing/cards/{}", ids[1]).as_str())
+        .with_status(404)
+        .with_body(r#"{"message":"Card not found"}"#)
+        .create();
+    let third = server
+        .mock("GET", format!("/v1/issuing/cards/{}", ids[2]).as_str())
+        .expect(0)
+        .create();
+
+    // Fetching one at a time, the failure stops the batch before the third card
+    let cards = tokio_test::block_on(client(&server).get_cards(&ids, 1, BulkMode::FailFast));
+    assert_eq!(cards[0].as_ref().unwrap().id, ids[0]);
+    assert!(matches!(
+        cards[1],
+        Err(RainError::ApiError { status: 404, .. })
+    ));
+    assert!(matches!(cards[2], Err(RainError::Cancelled)));
+    third.assert();
+}
+
+/// Serve a card with a 7-day limit and two pages of its spends since 2024-01-01
+fn spend_summary_server(card_id: Uuid) -> (mockito::ServerGuard, [mockito::Mock; 2]) {
+    let spend = |amount: i64, status: &str, authorized_at: &str| {
+        fixture_with(
+            "transaction_spend",
+            json!({
+                "id": Uuid::new_v4(),
+                "amount": amount,
+                "cardId": card_id,
+                "status": status,
+                "authorizedAt": authorized_at,
+            }),
+        )
+    };
+    let first_page: Vec<_> = (0..DEFAULT_PAGE_SIZE)
+        .map(|_| spend(100, "pending", "2024-01-05T00:00:00Z"))
+        .collect();
+    let cursor = first_page.last().unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let query = format!("cardId={card_id}&type=spend&authorizedAfter=2024-01-01T00%3A00%3A00Z");
+
+    let mut server = mockito::Server::new();
+    server
+        .mock("GET", format!("/v1/issuing/cards/{card_id}").as_str())
+        .with_body(
+            fixture_with(
+                "card",
+                json!({
+                    "id": card_id,
+                    "limit": { "amount": 10000, "frequency": "per7DayPeriod" },
+                }),
+            )
+            .to_string(),
+        )
+        .create();
+    let page_1 = server
+        .mock("GET", "/v1/issuing/transactions")
+        .match_query(Matcher::Exact(format!("{query}&limit=20")))
+        .with_body(json!(first_page).to_string())
+        .create();
+    let page_2 = server
+        .mock("GET", "/v1/issuing/transactions")
+        .match_query(Matcher::Exact(format!("{query}&cursor={cursor}&limit=20")))
+        .with_body(
+            json!([
+                spend(2500, "completed", "2024-01-03T00:00:00Z"),
+                spend(400, "reversed", "2024-01-02T00:00:00Z"),
+            ])
+            .to_string(),
+        )
+        .create();
+    (server, [page_1, page_2])
+}
+
+fn assert_spend_summary(summary: CardSpendSummary) {
+    assert_eq!(summary.spent, Amount::from(4500));
+    assert_eq!(summary.remaining, Some(Amount::from(5500)));
+    assert_eq!(summary.limit.unwrap().amount, 10000);
+    // The completed spend from the 3rd is the oldest counted, the reversed one is not
+    assert_eq!(
+        summary.period_end,
+        Some(Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn card_spend_summary_counts_pending_and_completed_spends() {
+    let card_id = Uuid::new_v4();
+    let (server, pages) = spend_summary_server(card_id);
+
+    let now = Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap();
+    let client = client_with(config(&server).with_clock(FixedClock(now)));
+    let summary = tokio_test::block_on(client.get_card_spend_summary(&card_id)).unwrap();
+    assert_spend_summary(summary);
+    pages.iter().for_each(mockito::Mock::assert);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn card_spend_summary_blocking_follows_every_page() {
+    let card_id = Uuid::new_v4();
+    let (server, pages) = spend_summary_server(card_id);
+
+    let now = Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap();
+    let client = client_with(config(&server).with_clock(FixedClock(now)));
+    let summary = client.get_card_spend_summary_blocking(&card_id).unwrap();
+    assert_spend_summary(summary);
+    pages.iter().for_each(mockito::Mock::assert);
+}
+
+#[test]
+fn update_card_if_match_reports_a_stale_etag() {
+    let card_id = Uuid::new_v4();
+    let path = format!("/v1/issuing/cards/{card_id}");
+    let mut server = mockito::Server::new();
+    let _get = server
+        .mock("GET", path.as_str())
+        .with_header("etag", "\"v1\"")
+        .with_body(card(card_id, "active"))
+        .create();
+    let patch = server
+        .mock("PATCH", path.as_str())
+        .match_header("if-match", "\"v1\"")
+        .with_status(412)
+        .with_body(r#"{"message":"Card was modified"}"#)
+        .create();
+
+    let client = client(&server);
+    tokio_test::block_on(async {
+        let (card, etag) = client
+            .get_with_etag::<Card>(&format!("/cards/{card_id}"))
+            .await
+            .unwrap();
+        let etag = etag.unwrap();
+        assert_eq!(etag, "\"v1\"");
+
+        let request = UpdateCardRequest {
+            status: Some(CardStatus::Locked),
+            limit: None,
+            billing: None,
+            configuration: None,
+        };
+        let result = client.update_card_if_match(&card.id, &request, &etag).await;
+        assert!(
+            matches!(result, Err(RainError::PreconditionFailed { response }) if response.to_string() == "Card was modified")
+        );
+    });
+    patch.assert();
+}
+
+/// Expect a PATCH of the card with exactly `body`
+fn expect_card_patch(
+    server: &mut mockito::ServerGuard,
+    card_id: Uuid,
+    body: &str,
+) -> mockito::Mock {
+    server
+        .mock("PATCH", format!("/v1/issuing/cards/{card_id}").as_str())
+        .match_body(Matcher::JsonString(body.to_string()))
+        .with_body(card(card_id, "active"))
+        .create()
+}
+
+#[test]
+fn set_card_display_name_patches_only_the_name() {
+    let card_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = expect_card_patch(
+        &mut server,
+        card_id,
+        r#"{"configuration":{"displayName":"Travel"}}"#,
+    );
+
+    tokio_test::block_on(client(&server).set_card_display_name(&card_id, "Travel")).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn set_card_art_patches_only_the_art() {
+    let card_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = expect_card_patch(
+        &mut server,
+        card_id,
+        r#"{"configuration":{"virtualCardArt":"https://example.com/art.png"}}"#,
+    );
+
+    tokio_test::block_on(client(&server).set_card_art(&card_id, "https://example.com/art.png"))
+        .unwrap();
+    mock.assert();
+}
+
+#[test]
+fn set_card_limit_patches_only_the_limit() {
+    let card_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = expect_card_patch(
+        &mut server,
+        card_id,
+        r#"{"limit":{"amount":50000,"frequency":"per30DayPeriod"}}"#,
+    );
+
+    tokio_test::block_on(client(&server).set_card_limit(
+        &card_id,
+        50_000,
+        LimitFrequency::Per30DayPeriod,
+    ))
+    .unwrap();
+    mock.assert();
+}
+
+#[test]
+fn remove_card_limit_sends_null() {
+    let card_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = expect_card_patch(&mut server, card_id, r#"{"limit":null}"#);
+
+    tokio_test::block_on(client(&server).remove_card_limit(&card_id)).unwrap();
+    mock.assert();
+}
+
+/// Serve a new card whose secrets are only ready on the second request
+fn pending_secrets_server(
+    user_id: Uuid,
+    card_id: Uuid,
+) -> (mockito::ServerGuard, [mockito::Mock; 3]) {
+    let secrets_path = format!("/v1/issuing/cards/{card_id}/secrets");
+    let mut server = mockito::Server::new();
+    let create = server
+        .mock(
+            "POST",
+            format!("/v1/issuing/users/{user_id}/cards").as_str(),
+        )
+        .with_body(card(card_id, "active"))
+        .create();
+    // Only reachable with the id returned by the create call, and not ready at first
+    let pending = server
+        .mock("GET", secrets_path.as_str())
+        .with_status(404)
+        .with_body(r#"{"message":"Card secrets not found"}"#)
+        .expect(1)
+        .create();
+    let secrets = server
+        .mock("GET", secrets_path.as_str())
+        .match_header("SessionId", "session")
+        .with_body(r#"{"encryptedPan":{"iv":"a","data":"b"},"encryptedCvc":{"iv":"c","data":"d"}}"#)
+        .create();
+    (server, [create, pending, secrets])
+}
+
+#[test]
+fn create_virtual_card_with_secrets_retries_pending_secrets() {
+    let user_id = Uuid::new_v4();
+    let card_id = Uuid::new_v4();
+    let (server, mocks) = pending_secrets_server(user_id, card_id);
+
+    let (card, card_secrets) =
+        tokio_test::block_on(client(&server).create_virtual_card_with_secrets(
+            &user_id,
+            &virtual_card_request(),
+            "session",
+        ))
+        .unwrap();
+    assert_eq!(card.id, card_id);
+    assert_eq!(card_secrets.encrypted_pan.iv, "a");
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn create_virtual_card_with_secrets_blocking_retries_pending_secrets() {
+    let user_id = Uuid::new_v4();
+    let card_id = Uuid::new_v4();
+    let (server, mocks) = pending_secrets_server(user_id, card_id);
+
+    let (card, card_secrets) = client(&server)
+        .create_virtual_card_with_secrets_blocking(&user_id, &virtual_card_request(), "session")
+        .unwrap();
+    assert_eq!(card.id, card_id);
+    assert_eq!(card_secrets.encrypted_cvc.iv, "c");
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[test]
+fn create_virtual_card_with_secrets_stops_without_retry_budget() {
+    let user_id = Uuid::new_v4();
+    let card_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let _create = server
+        .mock(
+            "POST",
+            format!("/v1/issuing/users/{user_id}/cards").as_str(),
+        )
+        .with_body(card(card_id, "active"))
+        .create();
+    let pending = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/cards/{card_id}/secrets").as_str(),
+        )
+        .with_status(404)
+        .with_body(r#"{"message":"Card secrets not found"}"#)
+        .expect(1)
+        .create();
+
+    let client = client_with(config(&server).with_retry_budget(0.0, 0));
+    let result = tokio_test::block_on(client.create_virtual_card_with_secrets(
+        &user_id,
+        &virtual_card_request(),
+        "session",
+    ));
+    assert!(result.is_err());
+    pending.assert();
+}
+
+#[test]
+fn reissue_card_locks_a_stolen_card_before_replacing_it() {
+    let old_id = Uuid::new_v4();
+    let new_id = Uuid::new_v4();
+    let old_card = fixture_with("card", json!({ "id": old_id }));
+    let user_id = old_card["userId"].as_str().unwrap().to_string();
+    let old_path = format!("/v1/issuing/cards/{old_id}");
+    let create_path = format!("/v1/issuing/users/{user_id}/cards");
+
+    let mut server = mockito::Server::new();
+    let _get = server
+        .mock("GET", old_path.as_str())
+        .with_body(old_card.to_string())
+        .create();
+    let lock = server
+        .mock("PATCH", old_path.as_str())
+        .match_body(Matcher::PartialJsonString(
+            r#"{"status":"locked"}"#.to_string(),
+        ))
+        .with_body(card(old_id, "locked"))
+        .create();
+    let cancel = server
+        .mock("PATCH", old_path.as_str())
+        .match_body(Matcher::PartialJsonString(
+            r#"{"status":"canceled"}"#.to_string(),
+        ))
+        .with_body(card(old_id, "canceled"))
+        .expect(1)
+        .create();
+    let create = server
+        .mock("POST", create_path.as_str())
+        .match_body(Matcher::PartialJsonString(
+            r#"{"type":"virtual","configuration":{"displayName":"Travel"}}"#.to_string(),
+        ))
+        .with_body(card(new_id, "active"))
+        .expect(1)
+        .create();
+
+    let client = client(&server);
+    let new_card =
+        tokio_test::block_on(client.reissue_card(&old_id, ReissueReason::Stolen, None)).unwrap();
+    assert_eq!(new_card.id, new_id);
+    assert!(matches!(new_card.status, CardStatus::Active));
+    lock.assert();
+    create.assert();
+
+    // A failed replacement leaves the old card locked rather than canceled
+    let failed = server
+        .mock("POST", create_path.as_str())
+        .with_status(500)
+        .with_body(r#"{"message":"Internal error"}"#)
+        .create();
+    let result = tokio_test::block_on(client.reissue_card(&old_id, ReissueReason::Lost, None));
+    assert!(result.is_err());
+    failed.assert();
+    cancel.assert();
+}
@@ -0,0 +1,302 @@
+//! Generic client behaviour against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, client_with, config, fixture};
+use mockito::Matcher;
+use rain_sdk::codec::BodyCodec;
+use rain_sdk::models::reports::{GetReportParams, ReportFormat};
+use rain_sdk::{AuthConfig, CancellationToken, Environment, RainClient, RainError, Result};
+use reqwest::multipart::Part;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+#[test]
+fn last_rate_limit_tracks_the_latest_response() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .with_header("X-RateLimit-Limit", "600")
+        .with_header("X-RateLimit-Remaining", "599")
+        .with_header("X-RateLimit-Reset", "1700000000")
+        .with_body("[]")
+        .create();
+
+    let client = client(&server);
+    assert!(client.last_rate_limit().is_none());
+
+    tokio_test::block_on(client.get_contracts()).unwrap();
+    let status = client.clone().last_rate_limit().unwrap();
+    assert_eq!(status.limit, 600);
+    assert_eq!(status.remaining, 599);
+    assert_eq!(status.reset, Some(1_700_000_000));
+}
+
+#[test]
+fn verify_credentials_rejects_a_bad_key() {
+    let mut server = mockito::Server::new();
+    let _valid = server
+        .mock("GET", "/v1/issuing/balances")
+        .match_header("api-key", "good-key")
+        .with_body(fixture("balance").to_string())
+        .create();
+    let _invalid = server
+        .mock("GET", "/v1/issuing/balances")
+        .match_header("api-key", "bad-key")
+        .with_status(401)
+        .with_body(r#"{"message":"Invalid API key"}"#)
+        .create();
+
+    let client = |key: &str| {
+        RainClient::new(config(&server), AuthConfig::with_api_key(key.to_string())).unwrap()
+    };
+    assert!(tokio_test::block_on(client("good-key").verify_credentials()).is_ok());
+    assert!(matches!(
+        tokio_test::block_on(client("bad-key").verify_credentials()),
+        Err(RainError::Unauthorized(_))
+    ));
+}
+
+#[test]
+fn endpoints_resolve_under_the_issuing_base_path() {
+    for env in [Environment::Dev, Environment::Production] {
+        assert_eq!(env.base_url().path(), "/v1/issuing");
+    }
+
+    let balance = fixture("balance").to_string();
+    let id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mocks = [
+        server
+            .mock("GET", "/v1/issuing/balances")
+            .with_body(&balance),
+        server
+            .mock("GET", format!("/v1/issuing/users/{id}/balances").as_str())
+            .with_body(&balance),
+        server
+            .mock(
+                "GET",
+                format!("/v1/issuing/companies/{id}/contracts").as_str(),
+            )
+            .with_body("[]"),
+        server
+            .mock("GET", "/v1/issuing/reports/2024/01/15")
+            .match_query(Matcher::Exact("format=csv".to_string()))
+            .with_body("id\n"),
+        server
+            .mock("GET", "/v1/issuing/transactions")
+            .match_query(Matcher::Exact("limit=20".to_string()))
+            .with_body("[]"),
+    ]
+    .map(|mock| mock.expect(1).create());
+
+    let client = client(&server);
+    tokio_test::block_on(async {
+        client.get_balances().await.unwrap();
+        client.get_user_balances(&id).await.unwrap();
+        client.get_company_contracts(&id).await.unwrap();
+        let params = GetReportParams {
+            format: Some(ReportFormat::Csv),
+        };
+        client
+            .get_report("2024", "01", "15", &params)
+            .await
+            .unwrap();
+        client.list_transactions(&Default::default()).await.unwrap();
+    });
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[test]
+fn get_cancellable_returns_once_cancelled() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("GET", "/v1/issuing/reports/slow")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(3));
+            w.write_all(b"{}")
+        })
+        .create();
+
+    let client = client(&server);
+    tokio_test::block_on(async {
+        let token = CancellationToken::new();
+        let trigger = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            trigger.cancel();
+        });
+
+        let started = Instant::now();
+        let result = client
+            .get_cancellable::<Value>("/reports/slow", &token)
+            .await;
+        assert!(matches!(result, Err(RainError::Cancelled)));
+        assert!(started.elapsed() < Duration::from_secs(3));
+    });
+}
+
+#[test]
+fn post_empty_sends_no_body() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/v1/issuing/trigger")
+        .match_header("api-key", common::API_KEY)
+        .match_header("content-type", Matcher::Missing)
+        .match_body(Matcher::Exact(String::new()))
+        .with_status(202)
+        .create();
+
+    tokio_test::block_on(client(&server).post_empty::<serde::de::IgnoredAny>("/trigger")).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn put_multipart_with_fields_sends_the_fields_and_the_file() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("PUT", "/v1/issuing/uploads")
+        .match_header("api-key", common::API_KEY)
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="type"\r\n\r\nreceipt"#.to_string()),
+            Matcher::Regex(r#"name="file"; filename="receipt.txt""#.to_string()),
+            Matcher::Regex("file contents".to_string()),
+        ]))
+        .with_status(204)
+        .create();
+
+    let file = Part::bytes(b"file contents".to_vec()).file_name("receipt.txt");
+    let fields = [("type", "receipt".to_string())];
+    tokio_test::block_on(
+        client(&server)
+            .put_multipart_with_fields::<serde::de::IgnoredAny>("/uploads", "file", file, &fields),
+    )
+    .unwrap();
+    mock.assert();
+}
+
+#[test]
+fn responses_are_parsed_from_the_body_bytes() {
+    let mut server = mockito::Server::new();
+    let _items = server
+        .mock("GET", "/v1/issuing/items")
+        .with_body(r#"[{"id":1},{"id":2}]"#)
+        .create();
+    let _empty = server
+        .mock("GET", "/v1/issuing/empty")
+        .with_status(204)
+        .create();
+    let _broken = server
+        .mock("GET", "/v1/issuing/broken")
+        .with_status(502)
+        .with_body(b"\xffbad gateway")
+        .create();
+
+    let client = client(&server);
+    tokio_test::block_on(async {
+        let items: Vec<Value> = client.get("/items").await.unwrap();
+        assert_eq!(items[1]["id"], 2);
+
+        let empty: Option<Value> = client.get("/empty").await.unwrap();
+        assert_eq!(empty, None);
+
+        // An error body that is not valid UTF-8 is still reported
+        let err = client.get::<Value>("/broken").await.unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("502") && message.contains("bad gateway"),
+            "{message}"
+        );
+    });
+}
+
+#[test]
+fn api_errors_keep_every_reported_message() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .with_status(400)
+        .with_body(r#"{"errors":[{"message":"Bad limit"},{"message":"Bad cursor"}]}"#)
+        .create();
+
+    match tokio_test::block_on(client(&server).get_contracts()) {
+        Err(RainError::ApiError {
+            status: 400,
+            response,
+        }) => {
+            assert_eq!(response.to_string(), "Bad limit");
+            assert_eq!(response.errors.len(), 2);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+/// Wraps every body in `{"data": ...}`
+#[derive(Debug)]
+struct EnvelopeCodec;
+
+impl BodyCodec for EnvelopeCodec {
+    fn encode(&self, value: Value) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&json!({ "data": value }))?)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Value> {
+        let mut envelope: Value = serde_json::from_slice(body)?;
+        match envelope.get_mut("data") {
+            Some(data) => Ok(data.take()),
+            None => Err(RainError::ValidationError("missing envelope".to_string())),
+        }
+    }
+}
+
+#[test]
+fn codec_wraps_requests_and_unwraps_responses() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/v1/issuing/echo")
+        .match_body(Matcher::Json(json!({ "data": { "name": "Ada" } })))
+        .with_body(r#"{"data":{"id":"abc"}}"#)
+        .create();
+
+    let client = client_with(config(&server).with_codec(EnvelopeCodec));
+    let response: Value =
+        tokio_test::block_on(client.post("/echo", &json!({ "name": "Ada" }))).unwrap();
+    assert_eq!(response, json!({ "id": "abc" }));
+    mock.assert();
+
+    // Error bodies are unwrapped the same way
+    let _missing = server
+        .mock("GET", "/v1/issuing/cards/abc")
+        .with_status(404)
+        .with_body(r#"{"data":{"message":"Card not found"}}"#)
+        .create();
+    let result: Result<Value> = tokio_test::block_on(client.get("/cards/abc"));
+    match result {
+        Err(RainError::ApiError {
+            status: 404,
+            response,
+        }) => {
+            assert_eq!(response.to_string(), "Card not found");
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn plain_requests_do_not_refill_the_retry_budget() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .with_body("[]")
+        .create();
+
+    let client = client_with(config(&server).with_retry_budget(1.0, 1));
+    assert!(client.retry_budget().try_withdraw());
+    tokio_test::block_on(client.get_contracts()).unwrap();
+    assert!(!client.retry_budget().try_withdraw());
+}
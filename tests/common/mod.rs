@@ -0,0 +1,48 @@
+//! Helpers shared by the integration tests
+//!
+//! Tests run the client against a local mockito server laid out like the real
+//! API, so mocks use the same `/v1/issuing/...` paths as Dev and Production.
+
+#![allow(dead_code)]
+
+use mockito::ServerGuard;
+use rain_sdk::{AuthConfig, Config, Environment, RainClient};
+use serde_json::Value;
+
+/// API key every test client sends
+pub const API_KEY: &str = "key";
+
+/// Load `tests/fixtures/{name}.json`
+pub fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+/// Load a fixture with some of its top-level fields replaced
+///
+/// `overrides` must be a JSON object; each of its keys replaces the fixture's.
+pub fn fixture_with(name: &str, overrides: Value) -> Value {
+    let mut value = fixture(name);
+    let fields = value.as_object_mut().expect("fixture is not an object");
+    for (key, field) in overrides.as_object().expect("overrides are not an object") {
+        fields.insert(key.clone(), field.clone());
+    }
+    value
+}
+
+/// Configuration pointing at `server` with the Dev base path
+pub fn config(server: &ServerGuard) -> Config {
+    let base = url::Url::parse(&server.url()).unwrap();
+    Config::new(Environment::custom_dev_like(base))
+}
+
+/// Client for `config`, authenticated with [`API_KEY`]
+pub fn client_with(config: Config) -> RainClient {
+    RainClient::new(config, AuthConfig::with_api_key(API_KEY.to_string())).unwrap()
+}
+
+/// Client pointing at `server` with the default configuration
+pub fn client(server: &ServerGuard) -> RainClient {
+    client_with(config(server))
+}
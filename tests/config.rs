@@ -0,0 +1,212 @@
+//! Client configuration against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, client_with, config};
+use mockito::Matcher;
+use rain_sdk::{Environment, RainError};
+use reqwest::multipart::{Form, Part};
+use serde_json::{json, Value};
+
+#[test]
+fn custom_dev_like_serves_under_the_issuing_prefix() {
+    let server = mockito::Server::new();
+    let env = Environment::custom_dev_like(url::Url::parse(&server.url()).unwrap());
+    assert_eq!(
+        env.base_url().as_str(),
+        format!("{}/v1/issuing", server.url())
+    );
+
+    // A trailing slash on the base does not produce an empty segment
+    let env = Environment::custom_dev_like(url::Url::parse("http://localhost:8080/").unwrap());
+    assert_eq!(env.base_url().as_str(), "http://localhost:8080/v1/issuing");
+}
+
+#[test]
+fn logging_reports_the_request_without_secrets_or_personal_details() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/v1/issuing/users")
+        .with_status(400)
+        .with_body(r#"{"message":"Invalid email"}"#)
+        .create();
+
+    let client = client_with(config(&server).with_logging(true));
+    let body = json!({
+        "firstName": "Ada",
+        "email": "not-an-email",
+        "password": "hunter2",
+        "nationalId": "123456789",
+        "birthDate": "1990-12-10",
+        "address": { "line1": "1 Main St", "countryCode": "US" },
+        "token": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+    });
+    let err = tokio_test::block_on(client.post::<Value, _>("/users", &body))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Invalid email"));
+    assert!(err.contains("Ada"));
+    // A token contract address is not a credential
+    assert!(err.contains("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+    for secret in [
+        "not-an-email",
+        "hunter2",
+        "123456789",
+        "1990-12-10",
+        "1 Main St",
+    ] {
+        assert!(!err.contains(secret), "{secret} leaked: {err}");
+    }
+}
+
+#[test]
+fn http2_prior_knowledge_still_connects() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/ping")
+        .with_body(r#"{"ok":true}"#)
+        .expect(2)
+        .create();
+
+    // The client connects both with a negotiated protocol and with forced HTTP/2
+    for http2 in [false, true] {
+        let client = client_with(config(&server).with_http2_prior_knowledge(http2));
+        let body: Value = tokio_test::block_on(client.get("/ping")).unwrap();
+        assert_eq!(body["ok"], true);
+    }
+    mock.assert();
+}
+
+#[test]
+fn locale_is_sent_as_accept_language() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .match_header("accept-language", "fr-FR")
+        .with_body("[]")
+        .create();
+
+    let client = client_with(config(&server).with_locale("fr-FR"));
+    tokio_test::block_on(client.get_contracts()).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn api_version_is_sent_on_every_request() {
+    let mut server = mockito::Server::new();
+    let get = server
+        .mock("GET", "/v1/issuing/contracts")
+        .match_header("rain-version", "2024-01-01")
+        .with_body("[]")
+        .create();
+    let upload = server
+        .mock("PUT", "/v1/issuing/upload")
+        .match_header("rain-version", "2024-01-01")
+        .with_status(204)
+        .create();
+
+    let pinned = client_with(config(&server).with_api_version("2024-01-01"));
+    tokio_test::block_on(pinned.get_contracts()).unwrap();
+    let form = Form::new().part("file", Part::bytes(b"data".to_vec()).file_name("a.txt"));
+    tokio_test::block_on(pinned.put_multipart_no_content("/upload", form)).unwrap();
+    get.assert();
+    upload.assert();
+
+    // Without a pinned version the header is omitted
+    let unpinned = server
+        .mock("GET", "/v1/issuing/contracts")
+        .match_header("rain-version", Matcher::Missing)
+        .with_body("[]")
+        .create();
+    tokio_test::block_on(client(&server).get_contracts()).unwrap();
+    unpinned.assert();
+}
+
+#[test]
+fn redirects_are_only_followed_on_the_same_host() {
+    let mut server = mockito::Server::new();
+    let mut elsewhere = mockito::Server::new();
+    let _moved = server
+        .mock("GET", "/v1/issuing/contracts")
+        .with_status(301)
+        .with_header("location", &format!("{}/v1/issuing/products", server.url()))
+        .create();
+    let products = server
+        .mock("GET", "/v1/issuing/products")
+        .with_body("[]")
+        .create();
+    let _leaked = server
+        .mock("GET", "/v1/issuing/users")
+        .with_status(302)
+        .with_header("location", &format!("{}/v1/issuing/users", elsewhere.url()))
+        .create();
+    let stolen = elsewhere
+        .mock("GET", "/v1/issuing/users")
+        .expect(0)
+        .create();
+
+    let client = client_with(config(&server).with_max_redirects(1));
+
+    // Redirects on the same host are followed
+    tokio_test::block_on(client.get_contracts()).unwrap();
+    products.assert();
+
+    // A redirect to another host is rejected before it is followed
+    let err = tokio_test::block_on(client.get::<Value>("/users")).unwrap_err();
+    assert!(matches!(err, RainError::HttpError(e) if e.is_redirect()));
+    stolen.assert();
+}
+
+#[test]
+fn default_headers_are_sent_on_every_request() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .match_header("x-tenant-id", "tenant-123")
+        .match_header("x-env", "staging")
+        .with_body("[]")
+        .create();
+
+    let client = client_with(
+        config(&server)
+            .with_default_header("X-Tenant-Id", "tenant-123")
+            .with_default_header("X-Env", "staging"),
+    );
+    tokio_test::block_on(client.get_contracts()).unwrap();
+    mock.assert();
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn default_headers_are_sent_by_the_blocking_client() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/contracts")
+        .match_header("x-tenant-id", "tenant-123")
+        .with_body("[]")
+        .create();
+
+    let client = client_with(config(&server).with_default_header("X-Tenant-Id", "tenant-123"));
+    client.get_contracts_blocking().unwrap();
+    mock.assert();
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn read_timeout_bounds_blocking_requests() {
+    let mut server = mockito::Server::new();
+    let _slow = server
+        .mock("GET", "/v1/issuing/contracts")
+        .with_body_from_request(|_| {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+            b"[]".to_vec()
+        })
+        .create();
+
+    let client =
+        client_with(config(&server).with_read_timeout(std::time::Duration::from_millis(100)));
+    let err = client.get_contracts_blocking().unwrap_err();
+    assert!(matches!(err, RainError::HttpError(e) if e.is_timeout()));
+}
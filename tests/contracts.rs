@@ -0,0 +1,42 @@
+//! Contract endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, fixture_with};
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn get_company_contract_on_chain_picks_the_matching_chain() {
+    let ethereum = Uuid::new_v4();
+    let polygon = Uuid::new_v4();
+    let company_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/companies/{company_id}/contracts").as_str(),
+        )
+        .with_body(
+            json!([
+                fixture_with("contract", json!({ "id": ethereum, "chainId": 1 })),
+                fixture_with("contract", json!({ "id": polygon, "chainId": 137 })),
+            ])
+            .to_string(),
+        )
+        .expect(2)
+        .create();
+
+    let client = client(&server);
+    let contract = tokio_test::block_on(client.get_company_contract_on_chain(&company_id, 137))
+        .unwrap()
+        .unwrap();
+    assert_eq!(contract.id, polygon);
+
+    let base =
+        tokio_test::block_on(client.get_company_contract_on_chain(&company_id, 8453)).unwrap();
+    assert!(base.is_none());
+    mock.assert();
+}
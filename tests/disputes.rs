@@ -0,0 +1,155 @@
+//! Dispute endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, fixture_with};
+use mockito::Matcher;
+use rain_sdk::error::ConflictReason;
+use rain_sdk::models::disputes::{Dispute, EvidenceType, ListDisputesParams, UpdateDisputeRequest};
+use rain_sdk::RainError;
+use serde_json::json;
+use uuid::Uuid;
+
+fn dispute(id: Uuid, status: &str) -> serde_json::Value {
+    fixture_with("dispute", json!({ "id": id, "status": status }))
+}
+
+#[test]
+fn all_disputes_follows_the_cursor() {
+    let user_id = Uuid::new_v4();
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+    let mut server = mockito::Server::new();
+    let page_1 = server
+        .mock("GET", "/v1/issuing/disputes")
+        .match_query(Matcher::Exact(format!("userId={user_id}&limit=2")))
+        .with_body(json!([dispute(ids[0], "pending"), dispute(ids[1], "pending")]).to_string())
+        .create();
+    let page_2 = server
+        .mock("GET", "/v1/issuing/disputes")
+        .match_query(Matcher::Exact(format!(
+            "userId={user_id}&cursor={}&limit=2",
+            ids[1]
+        )))
+        .with_body(json!([dispute(ids[2], "pending")]).to_string())
+        .create();
+
+    let params = ListDisputesParams {
+        user_id: Some(user_id),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let disputes = tokio_test::block_on(client(&server).all_disputes(&params)).unwrap();
+    let fetched: Vec<_> = disputes.iter().map(|dispute| dispute.id).collect();
+    assert_eq!(fetched, ids);
+    page_1.assert();
+    page_2.assert();
+}
+
+#[test]
+fn list_user_disputes_uses_the_default_page_size() {
+    let user_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/disputes")
+        .match_query(Matcher::Exact(format!("userId={user_id}&limit=20")))
+        .with_body("[]")
+        .create();
+
+    tokio_test::block_on(client(&server).list_user_disputes(&user_id)).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn update_dispute_if_editable_skips_resolved_disputes() {
+    let dispute_id = Uuid::new_v4();
+    let dispute =
+        |status: &str| -> Dispute { serde_json::from_value(dispute(dispute_id, status)).unwrap() };
+
+    let mut server = mockito::Server::new();
+    let patch = server
+        .mock(
+            "PATCH",
+            format!("/v1/issuing/disputes/{dispute_id}").as_str(),
+        )
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client(&server);
+    let request = UpdateDisputeRequest {
+        status: None,
+        text_evidence: Some("Tracking shows no delivery".to_string()),
+    };
+    tokio_test::block_on(client.update_dispute_if_editable(&dispute("inReview"), &request))
+        .unwrap();
+
+    let result =
+        tokio_test::block_on(client.update_dispute_if_editable(&dispute("accepted"), &request));
+    assert!(matches!(
+        result,
+        Err(RainError::Conflict {
+            reason: ConflictReason::DisputeNotEditable,
+            ..
+        })
+    ));
+    patch.assert();
+}
+
+#[test]
+fn get_dispute_evidence_with_type_returns_the_content_type() {
+    let dispute_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/disputes/{dispute_id}/evidence").as_str(),
+        )
+        .with_header("content-type", "application/pdf")
+        .with_body(b"%PDF-1.7")
+        .create();
+
+    let (bytes, content_type) =
+        tokio_test::block_on(client(&server).get_dispute_evidence_with_type(&dispute_id)).unwrap();
+    assert_eq!(bytes, b"%PDF-1.7");
+    assert_eq!(content_type.as_deref(), Some("application/pdf"));
+}
+
+#[test]
+fn upload_dispute_evidence_from_path_sends_a_multipart_form() {
+    let dispute_id = Uuid::new_v4();
+    let dir = std::env::temp_dir().join(format!("rain-sdk-evidence-{dispute_id}"));
+    std::fs::create_dir(&dir).unwrap();
+    let file_path = dir.join("receipt.txt");
+    std::fs::write(&file_path, "paid in full").unwrap();
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("PUT", format!("/v1/issuing/disputes/{dispute_id}/evidence").as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data; boundary=".to_string()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(
+                r#"name="evidence"; filename="receipt.txt"\r\n(?:[^\r\n]+\r\n)*\r\npaid in full\r\n"#
+                    .to_string(),
+            ),
+            Matcher::Regex(r#"name="name"\r\n\r\nreceipt.txt\r\n"#.to_string()),
+            Matcher::Regex(r#"name="type"\r\n\r\nreceipt\r\n"#.to_string()),
+        ]))
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let result = tokio_test::block_on(client(&server).upload_dispute_evidence_from_path(
+        &dispute_id,
+        &file_path,
+        EvidenceType::Receipt,
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+    result.unwrap();
+    mock.assert();
+}
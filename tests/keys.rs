@@ -0,0 +1,62 @@
+//! Key endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, client_with};
+use rain_sdk::{Config, Environment, RainError};
+use uuid::Uuid;
+
+#[test]
+fn delete_key_maps_each_failure() {
+    let (owned, foreign, missing) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+    let mut server = mockito::Server::new();
+    let _owned = server
+        .mock("DELETE", format!("/v1/issuing/keys/{owned}").as_str())
+        .with_status(204)
+        .create();
+    let _foreign = server
+        .mock("DELETE", format!("/v1/issuing/keys/{foreign}").as_str())
+        .with_status(403)
+        .with_body(r#"{"message":"Forbidden"}"#)
+        .create();
+    let _missing = server
+        .mock("DELETE", format!("/v1/issuing/keys/{missing}").as_str())
+        .with_status(404)
+        .with_body("Key not found")
+        .create();
+
+    let client = client(&server);
+    assert!(tokio_test::block_on(client.delete_key(&owned)).is_ok());
+    assert!(matches!(
+        tokio_test::block_on(client.delete_key(&foreign)),
+        Err(RainError::Forbidden(_))
+    ));
+    assert!(matches!(
+        tokio_test::block_on(client.delete_key(&missing)),
+        Err(RainError::NotFound(message)) if message == "Key not found"
+    ));
+}
+
+#[test]
+fn whoami_answers_without_a_request() {
+    let identity =
+        tokio_test::block_on(client_with(Config::new(Environment::Production)).whoami()).unwrap();
+    assert!(matches!(identity.environment, Environment::Production));
+    assert_eq!(identity.tenant_id, None);
+    assert_eq!(identity.name, None);
+    assert_eq!(identity.scopes, None);
+
+    let mut server = mockito::Server::new();
+    let any_request = server.mock("ANY", mockito::Matcher::Any).expect(0).create();
+    let base_url = url::Url::parse(&server.url()).unwrap();
+    let environment = Environment::custom_dev_like(base_url);
+    let identity =
+        tokio_test::block_on(client_with(Config::new(environment.clone())).whoami()).unwrap();
+    assert!(
+        matches!(identity.environment, Environment::Custom(url) if url == environment.base_url())
+    );
+    any_request.assert();
+}
@@ -0,0 +1,21 @@
+//! Metadata endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::client;
+use rain_sdk::RainError;
+
+#[test]
+fn supported_countries_and_currencies_are_unsupported() {
+    let mut server = mockito::Server::new();
+    let any_request = server.mock("ANY", mockito::Matcher::Any).expect(0).create();
+
+    let client = client(&server);
+    let countries = tokio_test::block_on(client.get_supported_countries());
+    assert!(matches!(countries, Err(RainError::Unsupported(_))));
+    let currencies = tokio_test::block_on(client.get_supported_currencies());
+    assert!(matches!(currencies, Err(RainError::Unsupported(_))));
+    any_request.assert();
+}
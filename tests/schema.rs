@@ -0,0 +1,76 @@
+//! Request schema validation against a mock server
+
+#![cfg(all(feature = "async", feature = "validate-schema", debug_assertions))]
+
+mod common;
+
+use common::{client, fixture};
+use rain_sdk::models::applications::CreateUserApplicationRequest;
+use rain_sdk::models::cards::{CardType, CreateCardRequest};
+use rain_sdk::RainError;
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn invalid_requests_are_rejected_before_they_are_sent() {
+    let user_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let create = server
+        .mock(
+            "POST",
+            format!("/v1/issuing/users/{user_id}/cards").as_str(),
+        )
+        .with_body(fixture("card").to_string())
+        .expect(1)
+        .create();
+    let applications = server
+        .mock("POST", "/v1/issuing/applications/user")
+        .expect(0)
+        .create();
+
+    let client = client(&server);
+    let mut request = CreateCardRequest {
+        r#type: CardType::Virtual,
+        status: None,
+        limit: None,
+        configuration: None,
+        shipping: None,
+        bulk_shipping_group_id: None,
+        billing: None,
+    };
+    tokio_test::block_on(client.create_user_card(&user_id, &request)).unwrap();
+
+    // A physical card needs somewhere to ship to
+    request.r#type = CardType::Physical;
+    let err = tokio_test::block_on(client.create_user_card(&user_id, &request)).unwrap_err();
+    assert!(matches!(&err, RainError::ValidationError(message)
+        if message.contains("/shipping is required") && message.contains("/bulkShippingGroupId is required")));
+    create.assert();
+
+    // An application through the API needs the applicant's details
+    let application: CreateUserApplicationRequest = serde_json::from_value(json!({
+        "firstName": "Ada",
+        "lastName": "Lovelace",
+        "birthDate": "1990-12-10",
+        "nationalId": "123456789",
+        "countryOfIssue": "US",
+        "address": {
+            "line1": "1 Main St",
+            "city": "Springfield",
+            "region": "IL",
+            "postalCode": "62701",
+            "countryCode": "US"
+        },
+        "ipAddress": "127.0.0.1",
+        "occupation": "Engineer",
+        "annualSalary": "100000",
+        "accountPurpose": "Personal",
+        "expectedMonthlyVolume": "5000",
+        "isTermsOfServiceAccepted": true
+    }))
+    .unwrap();
+    let err = tokio_test::block_on(client.create_user_application(&application)).unwrap_err();
+    assert!(matches!(&err, RainError::ValidationError(message)
+        if message.starts_with("CreateUserApplicationRequest") && message.contains("/email is required")));
+    applications.assert();
+}
@@ -0,0 +1,161 @@
+//! Round-trip every model through the JSON fixtures in `tests/fixtures`
+//!
+//! Each fixture carries every field of its model, so a field whose Rust name does
+//! not match the wire key is dropped on deserialization and shows up as a missing
+//! key after serializing back.
+
+use rain_sdk::models::applications::{CompanyApplicationResponse, UserApplicationResponse};
+use rain_sdk::models::balances::BalanceResponse;
+use rain_sdk::models::cards::{Card, CardSecrets, CardSpendSummary, ProcessorDetails};
+use rain_sdk::models::charges::Charge;
+use rain_sdk::models::common::{ApplicationReason, PersonInfo};
+use rain_sdk::models::companies::Company;
+use rain_sdk::models::contracts::Contract;
+use rain_sdk::models::disputes::Dispute;
+use rain_sdk::models::keys::Key;
+use rain_sdk::models::shipping_groups::ShippingGroup;
+use rain_sdk::models::subtenants::Subtenant;
+use rain_sdk::models::transactions::Transaction;
+use rain_sdk::models::users::User;
+use rain_sdk::models::webhooks::Webhook;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+mod common;
+use common::fixture;
+
+/// Deserialize the fixture as `T`, serialize it back and check nothing was lost
+fn assert_round_trip<T: Serialize + DeserializeOwned>(name: &str) {
+    let expected = fixture(name);
+    let model: T = serde_json::from_value(expected.clone())
+        .unwrap_or_else(|e| panic!("{name}: failed to deserialize: {e}"));
+    let actual = serde_json::to_value(&model).unwrap();
+    assert_matches(name, &expected, &actual);
+}
+
+/// Check that `actual` holds every key and value of `expected`
+///
+/// Numbers are compared by value, and may come back as strings when amounts are
+/// decimals.
+fn assert_matches(path: &str, expected: &Value, actual: &Value) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let path = format!("{path}.{key}");
+                match actual.get(key) {
+                    Some(actual) => assert_matches(&path, value, actual),
+                    None => panic!("{path}: dropped in the round trip"),
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            assert_eq!(expected.len(), actual.len(), "{path}: length changed");
+            for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                assert_matches(&format!("{path}[{i}]"), expected, actual);
+            }
+        }
+        (Value::Number(expected), actual) => {
+            let actual = match actual {
+                Value::Number(n) => n.as_f64(),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            };
+            assert_eq!(expected.as_f64(), actual, "{path}: number changed");
+        }
+        _ => assert_eq!(expected, actual, "{path}: value changed"),
+    }
+}
+
+#[test]
+fn applications() {
+    assert_round_trip::<UserApplicationResponse>("user_application");
+    assert_round_trip::<CompanyApplicationResponse>("company_application");
+    assert_round_trip::<PersonInfo>("person_info");
+}
+
+#[test]
+fn application_reasons_parse_to_their_variants() {
+    let user: User = serde_json::from_value(fixture("user")).unwrap();
+    let application: UserApplicationResponse =
+        serde_json::from_value(fixture("user_application")).unwrap();
+    let company: Company = serde_json::from_value(fixture("company")).unwrap();
+    assert_eq!(
+        user.application_reason_typed(),
+        Some(ApplicationReason::DocumentExpired)
+    );
+    assert_eq!(
+        application.application_reason_typed(),
+        Some(ApplicationReason::AddressMismatch)
+    );
+    assert_eq!(
+        company.application_reason_typed(),
+        Some(ApplicationReason::SanctionsHit)
+    );
+}
+
+#[test]
+fn balances() {
+    assert_round_trip::<BalanceResponse>("balance");
+}
+
+#[test]
+fn cards() {
+    assert_round_trip::<Card>("card");
+    assert_round_trip::<CardSecrets>("card_secrets");
+    assert_round_trip::<CardSpendSummary>("card_spend_summary");
+    assert_round_trip::<ProcessorDetails>("processor_details");
+}
+
+#[test]
+fn charges() {
+    assert_round_trip::<Charge>("charge");
+}
+
+#[test]
+fn companies() {
+    assert_round_trip::<Company>("company");
+}
+
+#[test]
+fn contracts() {
+    assert_round_trip::<Contract>("contract");
+}
+
+#[test]
+fn disputes() {
+    assert_round_trip::<Dispute>("dispute");
+}
+
+#[test]
+fn keys() {
+    assert_round_trip::<Key>("key");
+}
+
+#[test]
+fn shipping_groups() {
+    assert_round_trip::<ShippingGroup>("shipping_group");
+}
+
+#[test]
+fn subtenants() {
+    assert_round_trip::<Subtenant>("subtenant");
+}
+
+#[test]
+fn transactions() {
+    assert_round_trip::<Transaction>("transaction_spend");
+    assert_round_trip::<Transaction>("transaction_collateral");
+    assert_round_trip::<Transaction>("transaction_payment");
+    assert_round_trip::<Transaction>("transaction_fee");
+}
+
+#[test]
+fn users() {
+    assert_round_trip::<User>("user");
+}
+
+#[test]
+fn webhooks() {
+    assert_round_trip::<Webhook>("webhook");
+}
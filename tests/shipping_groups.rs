@@ -0,0 +1,64 @@
+//! Shipping group endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::{client, fixture_with};
+use mockito::{Mock, ServerGuard};
+use rain_sdk::client::wait::WaitConfig;
+use rain_sdk::models::shipping_groups::ShippingGroupStatus;
+use serde_json::json;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Serve the group as pending once, then as shipped
+fn pending_then_shipped(server: &mut ServerGuard, id: Uuid) -> (Mock, Mock) {
+    let path = format!("/v1/issuing/shipping-groups/{id}");
+    let pending = server
+        .mock("GET", path.as_str())
+        .with_body(
+            fixture_with(
+                "shipping_group",
+                json!({ "id": id, "status": "pending", "trackingNumber": null }),
+            )
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let shipped = server
+        .mock("GET", path.as_str())
+        .with_body(fixture_with("shipping_group", json!({ "id": id })).to_string())
+        .create();
+    (pending, shipped)
+}
+
+#[test]
+fn wait_for_shipped_polls_until_shipped() {
+    let id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let (pending, shipped) = pending_then_shipped(&mut server, id);
+
+    let wait = WaitConfig::new().with_interval(Duration::from_millis(10));
+    let group = tokio_test::block_on(client(&server).wait_for_shipped(&id, &wait)).unwrap();
+    assert_eq!(group.status, Some(ShippingGroupStatus::Shipped));
+    assert_eq!(group.tracking_number.as_deref(), Some("1Z999AA10123456784"));
+    pending.assert();
+    shipped.assert();
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn wait_for_shipped_blocking_polls_until_shipped() {
+    let id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let (pending, shipped) = pending_then_shipped(&mut server, id);
+
+    let wait = WaitConfig::new().with_interval(Duration::from_millis(10));
+    let group = client(&server)
+        .wait_for_shipped_blocking(&id, &wait)
+        .unwrap();
+    assert_eq!(group.status, Some(ShippingGroupStatus::Shipped));
+    pending.assert();
+    shipped.assert();
+}
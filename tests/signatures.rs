@@ -0,0 +1,135 @@
+//! Signature endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use chrono::{TimeDelta, Utc};
+use common::{client, client_with, config};
+use mockito::Matcher;
+use rain_sdk::clock::FixedClock;
+use rain_sdk::error::ConflictReason;
+use rain_sdk::models::signatures::{
+    PaymentSignatureParams, PaymentSignatureResponse, WithdrawalSignatureParams,
+};
+use rain_sdk::RainError;
+use uuid::Uuid;
+
+#[test]
+fn get_company_payment_signature_while_pending() {
+    let company_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/companies/{company_id}/signatures/payments").as_str(),
+        )
+        .match_query(Matcher::Exact(
+            "chainId=1&token=0xabc123&amount=1000000&adminAddress=0xdef456&isAmountNative=false"
+                .to_string(),
+        ))
+        .with_body(r#"{"status":"pending","retryAfter":5}"#)
+        .create();
+
+    let client = client(&server);
+    let params = PaymentSignatureParams {
+        chain_id: Some(1),
+        token: "0xabc123".to_string(),
+        amount: "1000000".to_string(),
+        admin_address: "0xdef456".to_string(),
+        is_amount_native: Some(false),
+        rain_collateral_contract_id: None,
+    };
+    let response =
+        tokio_test::block_on(client.get_company_payment_signature(&company_id, &params)).unwrap();
+    assert!(matches!(
+        response,
+        PaymentSignatureResponse::Pending { retry_after: 5, .. }
+    ));
+    mock.assert();
+}
+
+#[test]
+fn get_or_replace_payment_signature_waits_out_the_active_one() {
+    let company_id = Uuid::new_v4();
+    let path = format!("/v1/issuing/companies/{company_id}/signatures/payments");
+    let mut server = mockito::Server::new();
+    let conflict = server
+        .mock("GET", Matcher::Regex(format!("^{path}")))
+        .with_status(409)
+        .with_body(r#"{"message":"Another active signature already exists"}"#)
+        .expect(1)
+        .create();
+    let ready = server
+        .mock("GET", Matcher::Regex(format!("^{path}")))
+        .with_body(r#"{"status":"ready","signature":{"data":"0x01","salt":"0x02"}}"#)
+        .expect(1)
+        .create();
+
+    let now = Utc::now();
+    let client = client_with(config(&server).with_clock(FixedClock(now)));
+
+    let params = PaymentSignatureParams {
+        chain_id: Some(1),
+        token: "0xabc123".to_string(),
+        amount: "1000000".to_string(),
+        admin_address: "0xdef456".to_string(),
+        is_amount_native: None,
+        rain_collateral_contract_id: None,
+    };
+    let expires_at = now + TimeDelta::milliseconds(20);
+    let response = tokio_test::block_on(client.get_or_replace_payment_signature(
+        &company_id,
+        &params,
+        Some(expires_at),
+    ))
+    .unwrap();
+    assert!(matches!(response, PaymentSignatureResponse::Ready { .. }));
+    conflict.assert();
+    ready.assert();
+
+    // Without the active signature's expiry there is nothing to wait for
+    let _conflict = server
+        .mock("GET", Matcher::Regex(format!("^{path}")))
+        .with_status(409)
+        .with_body(r#"{"message":"Another active signature already exists"}"#)
+        .create();
+    let result =
+        tokio_test::block_on(client.get_or_replace_payment_signature(&company_id, &params, None));
+    assert!(matches!(
+        result,
+        Err(RainError::Conflict {
+            reason: ConflictReason::ActiveSignatureExists,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn get_company_withdrawal_signature_accepts_a_solana_recipient() {
+    let company_id = Uuid::new_v4();
+    let recipient = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/companies/{company_id}/signatures/withdrawals").as_str(),
+        )
+        .match_query(Matcher::UrlEncoded(
+            "recipientAddress".into(),
+            recipient.into(),
+        ))
+        .with_body(r#"{"status":"pending","retryAfter":5}"#)
+        .create();
+
+    let params = WithdrawalSignatureParams::builder()
+        .token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+        .amount("1000000")
+        .admin_address("4Nd1mYQmZ7qKq1Z5Zr5bK8xH7nYfJ1bCk9v2o3p4q5r6")
+        .recipient_address(recipient)
+        .build()
+        .unwrap();
+    tokio_test::block_on(client(&server).get_company_withdrawal_signature(&company_id, &params))
+        .unwrap();
+    mock.assert();
+}
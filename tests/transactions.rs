@@ -0,0 +1,129 @@
+//! Transaction endpoints against a mock server
+
+#![cfg(feature = "async")]
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::client;
+use futures_util::StreamExt;
+use mockito::Matcher;
+use rain_sdk::models::common::DEFAULT_PAGE_SIZE;
+use rain_sdk::models::transactions::ListTransactionsParams;
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn sync_transactions_since_reports_the_watermark_after_the_last_page() {
+    let fee = |posted_at: &str| json!({"type": "fee", "id": Uuid::new_v4(), "amount": 100, "postedAt": posted_at});
+    // A full first page, whose second fee is the latest posted
+    let first_page: Vec<_> = (0..DEFAULT_PAGE_SIZE)
+        .map(|i| {
+            fee(if i == 1 {
+                "2024-01-03T00:00:00Z"
+            } else {
+                "2024-01-02T00:00:00Z"
+            })
+        })
+        .collect();
+    let last = first_page.last().unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let mut server = mockito::Server::new();
+    let page_1 = server
+        .mock("GET", "/v1/issuing/transactions")
+        .match_query(Matcher::Regex(format!(
+            "^postedAfter=[^&]+&limit={DEFAULT_PAGE_SIZE}$"
+        )))
+        .with_body(serde_json::Value::from(first_page).to_string())
+        .create();
+    let page_2 = server
+        .mock("GET", "/v1/issuing/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("postedAfter".into(), "2024-01-01T00:00:00Z".into()),
+            Matcher::UrlEncoded("cursor".into(), last),
+        ]))
+        // Posted before the latest one on the first page
+        .with_body(json!([fee("2024-01-02T12:00:00Z")]).to_string())
+        .create();
+
+    let client = client(&server);
+    let checkpoint = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut sync = client.sync_transactions_since(checkpoint);
+    let mut handled = 0;
+    tokio_test::block_on(async {
+        while let Some(transaction) = sync.next().await {
+            transaction.unwrap();
+            handled += 1;
+            // Not known until every page has been read
+            assert_eq!(sync.watermark(), None);
+        }
+    });
+    assert_eq!(handled, DEFAULT_PAGE_SIZE + 1);
+    assert_eq!(
+        sync.watermark(),
+        Some(Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap())
+    );
+    page_1.assert();
+    page_2.assert();
+}
+
+#[test]
+fn export_transactions_csv_escapes_fields() {
+    let spend = r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11","type":"spend","amount":1250,"currency":"USD","receipt":false,"merchantName":"Coffee, Tea & \"More\"","merchantCategory":"Restaurants","merchantCategoryCode":"5814","cardId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b12","cardType":"virtual","userId":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b13","userFirstName":"Ada","userEmail":"ada@example.com","status":"completed","authorizedAt":"2024-01-01T00:00:00Z"}"#;
+    let collateral = r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b14","type":"collateral","amount":"12.5","currency":"USDC","chainId":1,"walletAddress":"0x1111111111111111111111111111111111111111","transactionHash":"0xabc","postedAt":"2024-01-02T00:00:00Z"}"#;
+    let payment = r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b15","type":"payment","amount":5000,"currency":"USD","status":"pending"}"#;
+    let fee = r#"{"id":"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b16","type":"fee","amount":99,"postedAt":"2024-01-03T00:00:00Z"}"#;
+    let formula = spend
+        .replace("9b11", "9b17")
+        .replace(r#"Coffee, Tea & \"More\""#, "=HYPERLINK(1)");
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v1/issuing/transactions?limit=10")
+        .with_body(format!("[{spend},{collateral},{payment},{fee},{formula}]"))
+        .create();
+
+    let client = client(&server);
+    let params = ListTransactionsParams {
+        limit: Some(10),
+        ..Default::default()
+    };
+    let mut csv = Vec::new();
+    let written = tokio_test::block_on(client.export_transactions_csv(&params, &mut csv)).unwrap();
+    assert_eq!(written, 5);
+    assert_eq!(
+        String::from_utf8(csv).unwrap().lines().collect::<Vec<_>>(),
+        [
+            "id,type,amount,amount_unit,currency,merchant,date,status",
+            r#"6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b11,spend,1250,cents,USD,"Coffee, Tea & ""More""",2024-01-01T00:00:00+00:00,completed"#,
+            "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b14,collateral,12.5,tokens,USDC,,2024-01-02T00:00:00+00:00,",
+            "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b15,payment,5000,cents,USD,,,pending",
+            "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b16,fee,99,cents,,,2024-01-03T00:00:00+00:00,",
+            "6f1c1e0e-8e54-4f47-9a0e-3c2f0c7f9b17,spend,1250,cents,USD,'=HYPERLINK(1),2024-01-01T00:00:00+00:00,completed",
+        ]
+    );
+    mock.assert();
+}
+
+#[test]
+fn get_transaction_receipt_with_type_returns_the_content_type() {
+    let transaction_id = Uuid::new_v4();
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock(
+            "GET",
+            format!("/v1/issuing/transactions/{transaction_id}/receipt").as_str(),
+        )
+        .with_header("content-type", "image/jpeg")
+        .with_body(b"\xff\xd8\xff")
+        .create();
+
+    let client = client(&server);
+    let (bytes, content_type) =
+        tokio_test::block_on(client.get_transaction_receipt_with_type(&transaction_id)).unwrap();
+    assert_eq!(bytes, b"\xff\xd8\xff");
+    assert_eq!(content_type.as_deref(), Some("image/jpeg"));
+}